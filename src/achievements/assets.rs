@@ -0,0 +1,57 @@
+use crate::settings::resources::Language;
+use crate::tricks::resources::Trick;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+#[derive(Asset, TypePath, Deserialize)]
+pub struct AchievementCatalog {
+    pub achievements: Vec<Achievement>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct Achievement {
+    // Progress is saved under this, so it must not change once released.
+    pub id: String,
+    // In the order of `Language::ALL`, like the game's other text.
+    pub name: [String; 3],
+    pub description: [String; 3],
+    pub goal: Goal,
+}
+
+fn language_index(language: Language) -> usize {
+    Language::ALL
+        .iter()
+        .position(|candidate| *candidate == language)
+        .unwrap_or_default()
+}
+
+impl Achievement {
+    pub fn name(&self, language: Language) -> &str {
+        &self.name[language_index(language)]
+    }
+
+    pub fn description(&self, language: Language) -> &str {
+        &self.description[language_index(language)]
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Debug)]
+pub enum Goal {
+    // Landings over all rounds.
+    Landings(u32),
+    // Landings in a row within one round.
+    Streak(u32),
+    // Landing the trick this many times.
+    Trick(Trick, u32),
+    // A flip with nothing left inside the bottle.
+    EmptyBottleFlip,
+}
+
+impl Goal {
+    pub fn target(self) -> u32 {
+        match self {
+            Goal::Landings(count) | Goal::Streak(count) | Goal::Trick(_, count) => count.max(1),
+            Goal::EmptyBottleFlip => 1,
+        }
+    }
+}
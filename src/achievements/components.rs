@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+// Where unlock notifications stack up.
+#[derive(Component)]
+pub struct AchievementToasts;
+
+#[derive(Component)]
+pub struct AchievementToast {
+    pub timer: Timer,
+}
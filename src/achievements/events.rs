@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+// Holds the achievement's index in the catalog.
+#[derive(Event, Clone, Copy)]
+pub struct AchievementUnlocked(pub usize);
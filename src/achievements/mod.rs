@@ -0,0 +1,58 @@
+pub mod assets;
+pub mod components;
+pub mod events;
+pub mod resources;
+pub mod systems;
+
+use crate::achievements::assets::AchievementCatalog;
+use crate::achievements::events::AchievementUnlocked;
+use crate::achievements::resources::{AchievementProgress, LandingStreak};
+use crate::achievements::systems::{
+    expire_achievement_toasts, load_achievement_catalog, reset_landing_streak, save_achievements,
+    show_achievement_toasts, spawn_achievement_toasts, spawn_achievements_screen,
+    track_achievements, ACHIEVEMENTS_KEY,
+};
+use crate::demo::resources::DemoMode;
+use crate::game::GameState;
+use crate::ron_asset::RonAssetPlugin;
+use crate::storage;
+use bevy::prelude::*;
+
+pub struct AchievementsPlugin;
+
+impl Plugin for AchievementsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RonAssetPlugin::<AchievementCatalog>::new(&[
+            "achievements.ron",
+        ]));
+        app.insert_resource(
+            storage::load_ron::<AchievementProgress>(ACHIEVEMENTS_KEY).unwrap_or_default(),
+        );
+        app.insert_resource(LandingStreak::default());
+        app.add_event::<AchievementUnlocked>();
+
+        app.add_systems(
+            Startup,
+            (load_achievement_catalog, spawn_achievement_toasts),
+        );
+        app.add_systems(OnEnter(GameState::Achievements), spawn_achievements_screen);
+        app.add_systems(
+            Update,
+            (
+                reset_landing_streak,
+                track_achievements.run_if(
+                    in_state(GameState::Playing).and_then(not(resource_exists::<DemoMode>)),
+                ),
+                (show_achievement_toasts, expire_achievement_toasts),
+            )
+                .chain(),
+        );
+        app.add_systems(
+            Last,
+            save_achievements.run_if(
+                resource_changed::<AchievementProgress>
+                    .and_then(not(resource_added::<AchievementProgress>)),
+            ),
+        );
+    }
+}
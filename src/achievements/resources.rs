@@ -0,0 +1,32 @@
+use crate::achievements::assets::{Achievement, AchievementCatalog};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Resource)]
+pub struct AchievementCatalogHandle(pub Handle<AchievementCatalog>);
+
+#[derive(Resource, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct AchievementProgress {
+    // Progress towards each achievement's goal, keyed by its id.
+    pub values: BTreeMap<String, u32>,
+    pub unlocked: BTreeSet<String>,
+}
+
+impl AchievementProgress {
+    pub fn value(&self, achievement: &Achievement) -> u32 {
+        self.values
+            .get(&achievement.id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn is_unlocked(&self, achievement: &Achievement) -> bool {
+        self.unlocked.contains(&achievement.id)
+    }
+}
+
+// Standing landings in a row since the level started.
+#[derive(Resource, Default)]
+pub struct LandingStreak(pub u32);
@@ -0,0 +1,285 @@
+use crate::achievements::assets::{AchievementCatalog, Goal};
+use crate::achievements::components::{AchievementToast, AchievementToasts};
+use crate::achievements::events::AchievementUnlocked;
+use crate::achievements::resources::{
+    AchievementCatalogHandle, AchievementProgress, LandingStreak,
+};
+use crate::bottle::components::{BottleContent, EmptyContent, Spilled};
+use crate::bottle::events::BottleLanded;
+use crate::game::resources::Round;
+use crate::game::GameState;
+use crate::i18n::translate;
+use crate::levels::events::StartLevel;
+use crate::menu::components::MenuAction;
+use crate::settings::resources::Settings;
+use crate::storage;
+use crate::tricks::events::TricksLanded;
+use crate::tricks::resources::Trick;
+use crate::ui::widgets::{
+    spawn_menu_button, spawn_screen, spawn_text, text_style, DISABLED_TEXT_COLOR, FONT_SIZE,
+    PANEL_COLOR,
+};
+use bevy::prelude::*;
+
+pub const ACHIEVEMENTS_KEY: &str = "achievements";
+const CATALOG_PATH: &str = "achievements.ron";
+const TOAST_DURATION: f32 = 3.;
+const SCREEN_COLOR: Color = Color::srgba(0.176, 0.114, 0.192, 0.75);
+const HEADING_FONT_SIZE: f32 = 32.;
+const DESCRIPTION_FONT_SIZE: f32 = 16.;
+
+pub fn load_achievement_catalog(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(AchievementCatalogHandle(asset_server.load(CATALOG_PATH)));
+}
+
+pub fn save_achievements(achievement_progress: Res<AchievementProgress>) {
+    storage::save_ron(ACHIEVEMENTS_KEY, achievement_progress.as_ref());
+}
+
+// Levels are started from menus too, so this can't wait for the playing state.
+pub fn reset_landing_streak(
+    mut landing_streak: ResMut<LandingStreak>,
+    mut start_level_event_reader: EventReader<StartLevel>,
+) {
+    if start_level_event_reader.read().count() > 0 {
+        landing_streak.0 = 0;
+    }
+}
+
+pub fn track_achievements(
+    round: Res<Round>,
+    catalog_handle: Res<AchievementCatalogHandle>,
+    catalogs: Res<Assets<AchievementCatalog>>,
+    mut achievement_progress: ResMut<AchievementProgress>,
+    mut landing_streak: ResMut<LandingStreak>,
+    // Content still inside a bottle that didn't start out empty.
+    content_query: Query<(), (With<BottleContent>, Without<EmptyContent>, Without<Spilled>)>,
+    mut landed_event_reader: EventReader<BottleLanded>,
+    mut tricks_event_reader: EventReader<TricksLanded>,
+    mut unlocked_event_writer: EventWriter<AchievementUnlocked>,
+) {
+    let mut landings = 0;
+    for landed in landed_event_reader.read() {
+        if landed.standing() {
+            landings += 1;
+            landing_streak.0 += 1;
+        } else {
+            landing_streak.0 = 0;
+        }
+    }
+
+    let tricks: Vec<Trick> = tricks_event_reader
+        .read()
+        .flat_map(|tricks_landed| tricks_landed.tricks.iter().copied())
+        .collect();
+
+    // Implausible throws don't earn anything.
+    if (landings == 0 && tricks.is_empty()) || round.invalid {
+        return;
+    }
+
+    let Some(catalog) = catalogs.get(&catalog_handle.0) else {
+        return;
+    };
+
+    let empty_bottle = content_query.is_empty();
+
+    for (index, achievement) in catalog.achievements.iter().enumerate() {
+        if achievement_progress.is_unlocked(achievement) {
+            continue;
+        }
+
+        let current = achievement_progress.value(achievement);
+        let value = match achievement.goal {
+            Goal::Landings(_) => current + landings,
+            Goal::Streak(_) => current.max(landing_streak.0),
+            Goal::Trick(trick, _) => {
+                current + tricks.iter().filter(|landed| **landed == trick).count() as u32
+            }
+            Goal::EmptyBottleFlip => {
+                current + u32::from(empty_bottle && tricks.iter().any(|trick| trick.is_flip()))
+            }
+        };
+
+        if value == current {
+            continue;
+        }
+
+        achievement_progress
+            .values
+            .insert(achievement.id.clone(), value);
+
+        if value >= achievement.goal.target() {
+            achievement_progress.unlocked.insert(achievement.id.clone());
+            unlocked_event_writer.send(AchievementUnlocked(index));
+        }
+    }
+}
+
+pub fn spawn_achievement_toasts(mut commands: Commands) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                // Clear of the HUD and the leaderboard panel along the top.
+                bottom: Val::Px(32.),
+                right: Val::Px(32.),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::End,
+                row_gap: Val::Px(8.),
+                ..default()
+            },
+            z_index: ZIndex::Global(20),
+            ..default()
+        },
+        AchievementToasts,
+    ));
+}
+
+pub fn show_achievement_toasts(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    catalog_handle: Res<AchievementCatalogHandle>,
+    catalogs: Res<Assets<AchievementCatalog>>,
+    toasts_query: Query<Entity, With<AchievementToasts>>,
+    mut unlocked_event_reader: EventReader<AchievementUnlocked>,
+) {
+    let (Some(catalog), Ok(toasts)) = (catalogs.get(&catalog_handle.0), toasts_query.get_single())
+    else {
+        return;
+    };
+
+    for AchievementUnlocked(index) in unlocked_event_reader.read() {
+        let Some(achievement) = catalog.achievements.get(*index) else {
+            continue;
+        };
+
+        commands.entity(toasts).with_children(|child_builder| {
+            child_builder
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            padding: UiRect::axes(Val::Px(16.), Val::Px(8.)),
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::End,
+                            ..default()
+                        },
+                        background_color: PANEL_COLOR.into(),
+                        ..default()
+                    },
+                    AchievementToast {
+                        timer: Timer::from_seconds(TOAST_DURATION, TimerMode::Once),
+                    },
+                ))
+                .with_children(|child_builder| {
+                    let mut heading_style = text_style(DESCRIPTION_FONT_SIZE);
+                    heading_style.color = settings.palette.accent();
+                    spawn_text(child_builder, "achievement_unlocked", heading_style);
+                    spawn_text(
+                        child_builder,
+                        achievement.name(settings.language).to_string(),
+                        text_style(FONT_SIZE),
+                    );
+                });
+        });
+    }
+}
+
+pub fn expire_achievement_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut toast_query: Query<(Entity, &mut AchievementToast)>,
+) {
+    for (entity, mut toast) in &mut toast_query {
+        if toast.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+pub fn spawn_achievements_screen(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    catalog_handle: Res<AchievementCatalogHandle>,
+    catalogs: Res<Assets<AchievementCatalog>>,
+    achievement_progress: Res<AchievementProgress>,
+) {
+    let language = settings.language;
+    let achievements = catalogs
+        .get(&catalog_handle.0)
+        .map_or(&[][..], |catalog| catalog.achievements.as_slice());
+
+    spawn_screen(
+        &mut commands,
+        SCREEN_COLOR,
+        StateScoped(GameState::Achievements),
+        |child_builder| {
+            spawn_text(child_builder, "achievements", text_style(HEADING_FONT_SIZE));
+
+            child_builder
+                .spawn(NodeBundle {
+                    style: Style {
+                        max_width: Val::Px(660.),
+                        flex_wrap: FlexWrap::Wrap,
+                        justify_content: JustifyContent::Center,
+                        column_gap: Val::Px(12.),
+                        row_gap: Val::Px(12.),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|child_builder| {
+                    for achievement in achievements {
+                        let unlocked = achievement_progress.is_unlocked(achievement);
+                        let status = if unlocked {
+                            translate(language, "unlocked").to_string()
+                        } else {
+                            format!(
+                                "{}/{}",
+                                achievement_progress.value(achievement),
+                                achievement.goal.target()
+                            )
+                        };
+
+                        let mut name_style = text_style(FONT_SIZE);
+                        if unlocked {
+                            name_style.color = settings.palette.accent();
+                        }
+                        let mut description_style = text_style(DESCRIPTION_FONT_SIZE);
+                        description_style.color = DISABLED_TEXT_COLOR;
+
+                        child_builder
+                            .spawn(NodeBundle {
+                                style: Style {
+                                    width: Val::Px(200.),
+                                    padding: UiRect::all(Val::Px(8.)),
+                                    flex_direction: FlexDirection::Column,
+                                    ..default()
+                                },
+                                background_color: PANEL_COLOR.into(),
+                                ..default()
+                            })
+                            .with_children(|child_builder| {
+                                spawn_text(
+                                    child_builder,
+                                    achievement.name(language).to_string(),
+                                    name_style,
+                                );
+                                spawn_text(
+                                    child_builder,
+                                    achievement.description(language).to_string(),
+                                    description_style,
+                                );
+                                spawn_text(
+                                    child_builder,
+                                    status,
+                                    text_style(DESCRIPTION_FONT_SIZE),
+                                );
+                            });
+                    }
+                });
+
+            spawn_menu_button(child_builder, "back", true, MenuAction::BackToTitle);
+        },
+    );
+}
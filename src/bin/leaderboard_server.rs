@@ -0,0 +1,263 @@
+// A small self-hostable leaderboard server. It replays every submitted round
+// headlessly and only records scores the throws actually earn.
+//
+// Run it from the repository root, so it finds the levels in `assets`:
+// `cargo run --bin leaderboard_server -- 127.0.0.1:7878`
+
+use bevy::log::{info, tracing_subscriber, warn, Level as LogLevel};
+use bottle_flip::daily::date::Date;
+use bottle_flip::daily::{daily_level, DAILY_PREFIX};
+use bottle_flip::leaderboard::backend::{LeaderboardBackend, LocalLeaderboard, Submission};
+use bottle_flip::levels::assets::{load_campaign, Level};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:7878";
+const ASSETS_DIRECTORY: &str = "assets";
+const LEADERBOARD_KEY: &str = "server_leaderboard";
+const MAX_BODY_LENGTH: usize = 1024 * 1024;
+const MAX_HEADER_LENGTH: usize = 16 * 1024;
+// For each read and write, and for the whole of the headers.
+const TIMEOUT: Duration = Duration::from_secs(10);
+// Replays take a while, so only so many requests are handled at once.
+const MAX_CONNECTIONS: usize = 8;
+
+const OK: &str = "200 OK";
+const BAD_REQUEST: &str = "400 Bad Request";
+const NOT_FOUND: &str = "404 Not Found";
+const UNPROCESSABLE: &str = "422 Unprocessable Entity";
+const SERVER_ERROR: &str = "500 Internal Server Error";
+const UNAVAILABLE: &str = "503 Service Unavailable";
+
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+}
+
+fn invalid_request(message: &str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message)
+}
+
+fn read_request(stream: &mut TcpStream) -> std::io::Result<Request> {
+    let started = Instant::now();
+    // Nothing past the largest request is ever read, however long the lines.
+    let mut reader = BufReader::new(stream.take((MAX_HEADER_LENGTH + MAX_BODY_LENGTH) as u64));
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut content_length = 0;
+    let mut header_length = request_line.len();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+
+        header_length += line.len();
+        if header_length > MAX_HEADER_LENGTH {
+            return Err(invalid_request("request headers too large"));
+        }
+        if started.elapsed() > TIMEOUT {
+            return Err(invalid_request("request headers too slow"));
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or_default();
+            }
+        }
+    }
+
+    if content_length > MAX_BODY_LENGTH {
+        return Err(invalid_request("request body too large"));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    Ok(Request {
+        method,
+        path,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn respond(stream: &mut TcpStream, status: &str, body: &str) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\n\
+         Content-Type: text/plain; charset=utf-8\r\n\
+         Content-Length: {}\r\n\
+         Connection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+// Generating a daily level runs the solver, so each one is built once, and only
+// for the days that can still be played: today, and yesterday for rounds that
+// started before midnight.
+#[derive(Default)]
+struct DailyLevels(Mutex<Vec<(Date, Level)>>);
+
+impl DailyLevels {
+    fn get(&self, date: Date) -> Result<Level, String> {
+        let today = Date::today().days();
+        if date.days() != today && date.days() != today - 1 {
+            return Err(format!("the daily level for {date} can't be played"));
+        }
+
+        let mut levels = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        levels.retain(|(cached, _)| cached.days() >= today - 1);
+        if let Some((_, level)) = levels.iter().find(|(cached, _)| *cached == date) {
+            return Ok(level.clone());
+        }
+
+        let level = daily_level(date);
+        levels.push((date, level.clone()));
+        Ok(level)
+    }
+}
+
+// Only daily levels and levels in the campaign can be played, which also keeps
+// submissions from naming arbitrary files.
+fn load_level(daily_levels: &DailyLevels, level_path: &str) -> Result<Level, String> {
+    if let Some(date) = level_path.strip_prefix(DAILY_PREFIX) {
+        return daily_levels.get(date.parse::<Date>()?);
+    }
+
+    load_campaign(Path::new(ASSETS_DIRECTORY))?
+        .into_iter()
+        .find(|(path, _)| path == level_path)
+        .map(|(_, level)| level)
+        .ok_or_else(|| format!("unknown level {level_path}"))
+}
+
+fn submit(
+    leaderboard: &LocalLeaderboard,
+    daily_levels: &DailyLevels,
+    body: &str,
+) -> (&'static str, String) {
+    let submission: Submission = match ron::from_str(body) {
+        Ok(submission) => submission,
+        Err(error) => return (BAD_REQUEST, error.to_string()),
+    };
+
+    let level = match load_level(daily_levels, &submission.level) {
+        Ok(level) => level,
+        Err(error) => return (BAD_REQUEST, error),
+    };
+
+    if let Err(error) = submission.verify(level) {
+        info!(
+            "Rejected {} on {}: {error}",
+            submission.player, submission.level
+        );
+        return (UNPROCESSABLE, error.to_string());
+    }
+
+    match leaderboard.submit(&submission) {
+        Ok(()) => {
+            info!(
+                "Recorded {} by {} on {}",
+                submission.score, submission.player, submission.level
+            );
+            (OK, String::new())
+        }
+        Err(error) => (SERVER_ERROR, error.to_string()),
+    }
+}
+
+fn top_scores(leaderboard: &LocalLeaderboard, level: &str) -> (&'static str, String) {
+    let result = leaderboard
+        .top_scores(level)
+        .map_err(|error| error.to_string())
+        .and_then(|entries| ron::to_string(&entries).map_err(|error| error.to_string()));
+
+    match result {
+        Ok(body) => (OK, body),
+        Err(error) => (SERVER_ERROR, error),
+    }
+}
+
+fn handle(
+    leaderboard: &LocalLeaderboard,
+    daily_levels: &DailyLevels,
+    request: &Request,
+) -> (&'static str, String) {
+    match (request.method.as_str(), request.path.as_str()) {
+        ("POST", "/scores") => submit(leaderboard, daily_levels, &request.body),
+        ("GET", path) => match path.strip_prefix("/scores/") {
+            Some(level) => top_scores(leaderboard, level),
+            None => (NOT_FOUND, String::from("not found")),
+        },
+        _ => (NOT_FOUND, String::from("not found")),
+    }
+}
+
+fn serve(leaderboard: &LocalLeaderboard, daily_levels: &DailyLevels, stream: &mut TcpStream) {
+    let (status, body) = match read_request(stream) {
+        Ok(request) => handle(leaderboard, daily_levels, &request),
+        Err(error) => (BAD_REQUEST, error.to_string()),
+    };
+
+    if let Err(error) = respond(stream, status, &body) {
+        warn!("Failed to respond: {error}");
+    }
+}
+
+fn main() {
+    tracing_subscriber::fmt()
+        .with_max_level(LogLevel::INFO)
+        .init();
+
+    let address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_ADDRESS.to_string());
+    let listener = TcpListener::bind(&address)
+        .unwrap_or_else(|error| panic!("Failed to listen on {address}: {error}"));
+    let leaderboard = Arc::new(LocalLeaderboard::persistent(LEADERBOARD_KEY));
+    let daily_levels = Arc::new(DailyLevels::default());
+    let connections = Arc::new(AtomicUsize::new(0));
+
+    info!("Leaderboard listening on {address}");
+
+    // Each request gets its own thread, so a slow client can't hold up the rest.
+    for stream in listener.incoming() {
+        let Ok(mut stream) = stream else {
+            continue;
+        };
+
+        if let Err(error) = stream
+            .set_read_timeout(Some(TIMEOUT))
+            .and_then(|_| stream.set_write_timeout(Some(TIMEOUT)))
+        {
+            warn!("Failed to set timeouts: {error}");
+            continue;
+        }
+
+        if connections.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+            connections.fetch_sub(1, Ordering::SeqCst);
+            if let Err(error) = respond(&mut stream, UNAVAILABLE, "too many requests") {
+                warn!("Failed to respond: {error}");
+            }
+            continue;
+        }
+
+        let leaderboard = leaderboard.clone();
+        let daily_levels = daily_levels.clone();
+        let connections = connections.clone();
+        std::thread::spawn(move || {
+            serve(&leaderboard, &daily_levels, &mut stream);
+            connections.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+}
@@ -0,0 +1,49 @@
+// Checks that every platform in the campaign can be landed on, by having the
+// solver search for a throw onto each. Run it from the repository root:
+// `cargo run --release --bin solve_levels`
+
+use bottle_flip::levels::assets::load_campaign;
+use bottle_flip::solver::search::{solve, SolverConfig, Target};
+use std::path::Path;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let levels = match load_campaign(Path::new("assets")) {
+        Ok(levels) => levels,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut unsolved = 0;
+    for (path, level) in &levels {
+        let targets: Vec<Target> = if level.platforms.is_empty() {
+            vec![Target::Anywhere]
+        } else {
+            (0..level.platforms.len()).map(Target::Platform).collect()
+        };
+
+        for target in targets {
+            match solve(level, target, &SolverConfig::default()).first() {
+                Some(solution) => {
+                    let release = solution.release;
+                    println!(
+                        "{path} {target:?}: release at {} with velocity {} and spin {}",
+                        release.position, release.linear_velocity, release.angular_velocity
+                    );
+                }
+                None => {
+                    unsolved += 1;
+                    println!("{path} {target:?}: no throw found");
+                }
+            }
+        }
+    }
+
+    if unsolved > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
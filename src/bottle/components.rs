@@ -3,17 +3,44 @@ use bevy::prelude::*;
 #[derive(Component)]
 pub struct GrabAnchor;
 
+// Spring between the grab anchor and a point on the bottle, in the bottle's
+// local space.
 #[derive(Component)]
-pub struct GrabJoint;
+pub struct GrabJoint {
+    pub local_anchor: Vec2,
+    // Where the bottle was picked up, which the anchor stays within reach of.
+    pub origin: Vec2,
+}
 
 #[derive(Component)]
 pub struct Bottle;
 
 #[derive(Component)]
-pub struct BottleContent;
+pub struct BottleCap;
+
+#[derive(Component)]
+pub struct BottleContent {
+    // Tells the two balls apart, for recording and restoring their state.
+    pub index: usize,
+}
 
 #[derive(Component)]
 pub struct BottleContentJoint;
 
+// Content of a bottle that starts out empty, so next to weightless.
+#[derive(Component)]
+pub struct EmptyContent;
+
+// A cap on its own body, held on by a `CapJoint` until a hard hit knocks it off.
+#[derive(Component)]
+pub struct LooseCap;
+
+#[derive(Component)]
+pub struct CapJoint;
+
+// Content that poured out of an open bottle.
+#[derive(Component)]
+pub struct Spilled;
+
 #[derive(Component)]
 pub struct Grabbable;
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+#[derive(Event)]
+pub struct BottleLanded {
+    pub upright: bool,
+    // Balanced on the cap, which is as good as upright.
+    pub upside_down: bool,
+    // Came to rest after its content poured out, which never counts as standing.
+    pub spilled: bool,
+}
+
+impl BottleLanded {
+    pub fn standing(&self) -> bool {
+        !self.spilled && (self.upright || self.upside_down)
+    }
+}
+
+// Pointer input in world coordinates, from mouse, touch or anything else that
+// wants to throw the bottle.
+#[derive(Event, Clone, Copy, Debug)]
+pub enum GrabInput {
+    Press(Vec2),
+    Drag(Vec2),
+    Release(Vec2),
+}
+
+#[derive(Event)]
+pub struct BottleThrown;
+
+// The cap came off and the content poured out of the bottle's neck here.
+#[derive(Event, Clone, Copy)]
+pub struct ContentSpilled {
+    pub position: Vec2,
+}
+
+// A throw faster than a person could make, or one that hit the velocity caps.
+#[derive(Event)]
+pub struct ImplausibleThrow;
@@ -1,12 +1,20 @@
 pub mod components;
-mod resources;
+pub mod events;
+pub mod resources;
 pub mod systems;
 
-use crate::bottle::resources::TouchGrab;
+use crate::bottle::events::{
+    BottleLanded, BottleThrown, ContentSpilled, GrabInput, ImplausibleThrow,
+};
+use crate::bottle::resources::{Aim, Flight, ThrowLimits, ThrowMode, TouchGrab};
 use crate::bottle::systems::{
-    drag_bottle_using_mouse, drag_bottle_using_touch, grab_bottle_using_mouse,
-    grab_bottle_using_touch, release_bottle_using_mouse, release_bottle_using_touch, spawn_bottle,
+    apply_grab_spring, cap_velocities, check_throw_plausibility, detect_bottle_landing,
+    direct_grab, draw_aim, draw_trajectory_preview, flick, pop_loose_caps, read_mouse_grab_input,
+    read_touch_grab_input, slingshot, spill_content,
 };
+use crate::game::GameState;
+use crate::physics::ImpactDetection;
+use avian2d::prelude::*;
 use bevy::prelude::*;
 
 pub struct BottlePlugin;
@@ -14,18 +22,48 @@ pub struct BottlePlugin;
 impl Plugin for BottlePlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(TouchGrab::default());
+        app.insert_resource(Flight::default());
+        app.insert_resource(Aim::default());
+        app.insert_resource(ThrowMode::default());
+        app.insert_resource(ThrowLimits::default());
+        app.add_event::<BottleLanded>();
+        app.add_event::<GrabInput>();
+        app.add_event::<BottleThrown>();
+        app.add_event::<ImplausibleThrow>();
+        app.add_event::<ContentSpilled>();
 
-        app.add_systems(Startup, spawn_bottle);
         app.add_systems(
             Update,
             (
-                grab_bottle_using_mouse,
-                grab_bottle_using_touch,
-                drag_bottle_using_mouse,
-                drag_bottle_using_touch,
-                release_bottle_using_mouse,
-                release_bottle_using_touch,
-            ),
+                (read_mouse_grab_input, read_touch_grab_input),
+                (
+                    direct_grab.run_if(resource_equals(ThrowMode::DirectGrab)),
+                    slingshot.run_if(resource_equals(ThrowMode::Slingshot)),
+                    flick.run_if(resource_equals(ThrowMode::Flick)),
+                ),
+                (detect_bottle_landing, check_throw_plausibility),
+                (draw_aim, draw_trajectory_preview),
+            )
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        );
+        // Runs before every physics step, so the spring and the caps see each
+        // step's velocities however many steps a frame takes.
+        app.add_systems(
+            PhysicsSchedule,
+            (apply_grab_spring, cap_velocities)
+                .chain()
+                .before(PhysicsStepSet::First)
+                .run_if(in_state(GameState::Playing)),
+        );
+        // Caps are knocked off by the impacts of each step, and let the content
+        // out as soon as the bottle tips.
+        app.add_systems(
+            PhysicsSchedule,
+            (pop_loose_caps, spill_content)
+                .chain()
+                .after(ImpactDetection)
+                .run_if(in_state(GameState::Playing)),
         );
     }
 }
@@ -1,4 +1,41 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 
 #[derive(Resource, Default)]
 pub struct TouchGrab(pub Option<u64>);
+
+#[derive(Resource, Default)]
+pub struct Flight {
+    pub airborne: bool,
+    pub resting_time: f32,
+}
+
+// How pointer input turns into a throw, chosen per level.
+#[derive(Resource, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ThrowMode {
+    // The bottle hangs from the pointer and keeps its velocity when let go.
+    #[default]
+    DirectGrab,
+    // Pull back from the bottle and release to launch it the opposite way.
+    Slingshot,
+    // The swipe velocity at release sets the launch.
+    Flick,
+}
+
+pub struct Aiming {
+    pub start: Vec2,
+    pub current: Vec2,
+    // Offset of the pressed point from the bottle's centre.
+    pub offset: Vec2,
+    pub samples: VecDeque<(f32, Vec2)>,
+}
+
+#[derive(Resource, Default)]
+pub struct Aim(pub Option<Aiming>);
+
+#[derive(Resource, Default)]
+pub struct ThrowLimits {
+    // Set when the velocity caps kicked in while the bottle was held.
+    pub exceeded: bool,
+}
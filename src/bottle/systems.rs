@@ -1,40 +1,88 @@
 use crate::bottle::components::{
-    Bottle, BottleContent, BottleContentJoint, GrabAnchor, GrabJoint, Grabbable,
+    Bottle, BottleCap, BottleContent, BottleContentJoint, CapJoint, EmptyContent, GrabAnchor,
+    GrabJoint, Grabbable, LooseCap, Spilled,
 };
-use crate::bottle::resources::TouchGrab;
+use crate::bottle::events::{
+    BottleLanded, BottleThrown, ContentSpilled, GrabInput, ImplausibleThrow,
+};
+use crate::bottle::resources::{Aim, Aiming, Flight, ThrowLimits, ThrowMode, TouchGrab};
+use crate::physics::events::Impact;
 use crate::physics::CustomCollisionLayer;
+use crate::settings::resources::Settings;
+use crate::tuning::assets::PhysicsTuning;
 use avian2d::prelude::*;
 use bevy::input::touch::TouchPhase;
 use bevy::prelude::*;
+use std::f32::consts::PI;
 
-const BOTTLE_BODY_SIZE: Vec2 = Vec2::new(50., 90.);
+pub const BOTTLE_BODY_SIZE: Vec2 = Vec2::new(50., 90.);
 const BOTTLE_NECK_HEIGHT: f32 = 30.;
 const BOTTLE_CAP_SIZE: Vec2 = Vec2::new(20., 10.);
-const BOTTLE_DENSITY: f32 = 0.4;
+// From the bottle's centre to the cap's.
+const BOTTLE_CAP_OFFSET: f32 =
+    BOTTLE_BODY_SIZE.y / 2. + BOTTLE_NECK_HEIGHT - BOTTLE_CAP_SIZE.y / 2.;
+// A loose cap comes off when it hits something this hard.
+const CAP_POP_SPEED: f32 = 650.;
+// Cosine of how far from the bottle's axis a hit can come and still land
+// square on the cap.
+const CAP_HIT_ALIGNMENT: f32 = 0.7;
+// How far past sideways an open bottle tips before its content pours out.
+const SPILL_TILT: f32 = -0.3;
 const CONTENT_RADIUS: f32 = 18.;
-const CONTENT_DENSITY: f32 = 4.;
+// Air, more or less, but with enough mass to keep the content joints stable.
+pub const EMPTY_CONTENT_DENSITY: f32 = 0.04;
+const RESTING_LINEAR_SPEED: f32 = 5.;
+const RESTING_ANGULAR_SPEED: f32 = 0.1;
+const RESTING_DURATION: f32 = 0.3;
+const UPRIGHT_TOLERANCE: f32 = 0.2;
+const TRAJECTORY_DURATION: f32 = 1.;
+const TRAJECTORY_STEPS: usize = 24;
+const SLINGSHOT_STRENGTH: f32 = 8.;
+const SLINGSHOT_MAX_PULL: f32 = 200.;
+pub const SLINGSHOT_MAX_SPEED: f32 = SLINGSHOT_MAX_PULL * SLINGSHOT_STRENGTH;
+const SLINGSHOT_SPIN: f32 = 0.006;
+const FLICK_WINDOW: f32 = 0.08;
+pub const FLICK_MAX_SPEED: f32 = 2400.;
+const FLICK_SPIN: f32 = 0.0001;
+// Fastest throws a person could plausibly make, anything beyond is flagged.
+pub const PLAUSIBLE_LINEAR_SPEED: f32 = 2500.;
+pub const PLAUSIBLE_ANGULAR_SPEED: f32 = 30.;
+// How far from where it was picked up the bottle can be carried.
+pub const GRAB_REACH: f32 = 300.;
+
+pub fn spawn_bottle(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    physics_tuning: &PhysicsTuning,
+    position: Vec2,
+    loose_cap: bool,
+    empty: bool,
+) -> Entity {
+    commands.insert_resource(Flight::default());
+    commands.insert_resource(TouchGrab::default());
+    commands.insert_resource(Aim::default());
 
-pub fn spawn_bottle(mut commands: Commands, asset_server: Res<AssetServer>) {
+    // The solver's results depend on the order bodies and joints are processed
+    // in, so everything is spawned in a fixed order for replays to match.
     commands.spawn((
-        TransformBundle::from_transform(Transform::from_xyz(0., 0., 0.)),
-        RigidBody::Kinematic,
+        TransformBundle::from_transform(Transform::from_translation(position.extend(0.))),
         GrabAnchor,
     ));
 
     let container = commands
         .spawn((
             VisibilityBundle::default(),
-            TransformBundle::default(),
-            ColliderDensity(BOTTLE_DENSITY),
+            TransformBundle::from_transform(Transform::from_translation(position.extend(0.))),
+            ColliderDensity(physics_tuning.bottle_density),
             RigidBody::Dynamic,
             Collider::rectangle(BOTTLE_BODY_SIZE.x, BOTTLE_BODY_SIZE.y),
             CollisionLayers::new(
                 CustomCollisionLayer::Bottle,
-                [CustomCollisionLayer::Platform],
+                [CustomCollisionLayer::Platform, CustomCollisionLayer::Target],
             ),
             Bottle,
             Grabbable,
-            AngularDamping(0.5),
+            AngularDamping(physics_tuning.bottle_angular_damping),
         ))
         .with_children(|child_builder| {
             // Bottle body
@@ -52,7 +100,7 @@ pub fn spawn_bottle(mut commands: Commands, asset_server: Res<AssetServer>) {
                     0.,
                 )),
                 Grabbable,
-                ColliderDensity(BOTTLE_DENSITY),
+                ColliderDensity(physics_tuning.bottle_density),
                 Collider::triangle(
                     Vec2::Y * BOTTLE_NECK_HEIGHT,
                     Vec2::new(-BOTTLE_BODY_SIZE.x / 2., 0.),
@@ -60,77 +108,227 @@ pub fn spawn_bottle(mut commands: Commands, asset_server: Res<AssetServer>) {
                 ),
                 CollisionLayers::new(
                     CustomCollisionLayer::Bottle,
-                    [CustomCollisionLayer::Platform],
+                    [CustomCollisionLayer::Platform, CustomCollisionLayer::Target],
                 ),
             ));
             // Bottle cap
-            child_builder.spawn((
-                TransformBundle::from_transform(Transform::from_xyz(
-                    0.,
-                    BOTTLE_BODY_SIZE.y / 2. + BOTTLE_NECK_HEIGHT - BOTTLE_CAP_SIZE.y / 2.,
-                    0.,
-                )),
-                Grabbable,
-                ColliderDensity(BOTTLE_DENSITY),
-                Collider::rectangle(BOTTLE_CAP_SIZE.x, BOTTLE_CAP_SIZE.y),
-                CollisionLayers::new(
-                    CustomCollisionLayer::Bottle,
-                    [CustomCollisionLayer::Platform],
-                ),
-            ));
+            if !loose_cap {
+                child_builder.spawn((
+                    BottleCap,
+                    TransformBundle::from_transform(Transform::from_xyz(0., BOTTLE_CAP_OFFSET, 0.)),
+                    Grabbable,
+                    ColliderDensity(physics_tuning.bottle_density),
+                    Collider::rectangle(BOTTLE_CAP_SIZE.x, BOTTLE_CAP_SIZE.y),
+                    CollisionLayers::new(
+                        CustomCollisionLayer::Bottle,
+                        [CustomCollisionLayer::Platform, CustomCollisionLayer::Target],
+                    ),
+                ));
+            }
         })
         .id();
 
+    let content_density = if empty {
+        EMPTY_CONTENT_DENSITY
+    } else {
+        physics_tuning.content_density
+    };
+
     let content_1 = commands
         .spawn((
-            TransformBundle::from_transform(Transform::from_xyz(0., CONTENT_RADIUS, 0.)),
-            ColliderDensity(CONTENT_DENSITY),
+            TransformBundle::from_transform(Transform::from_translation(
+                (position + Vec2::Y * CONTENT_RADIUS).extend(0.),
+            )),
+            ColliderDensity(content_density),
             RigidBody::Dynamic,
             Collider::circle(CONTENT_RADIUS),
             CollisionLayers::new(
                 CustomCollisionLayer::Content,
                 [CustomCollisionLayer::Content],
             ),
-            BottleContent,
+            BottleContent { index: 0 },
         ))
         .id();
 
     let content_2 = commands
         .spawn((
-            TransformBundle::from_transform(Transform::from_xyz(0., -CONTENT_RADIUS, 0.)),
-            ColliderDensity(CONTENT_DENSITY),
+            TransformBundle::from_transform(Transform::from_translation(
+                (position - Vec2::Y * CONTENT_RADIUS).extend(0.),
+            )),
+            ColliderDensity(content_density),
             RigidBody::Dynamic,
             Collider::circle(CONTENT_RADIUS),
             CollisionLayers::new(
                 CustomCollisionLayer::Content,
                 [CustomCollisionLayer::Content],
             ),
-            BottleContent,
+            BottleContent { index: 1 },
         ))
         .id();
 
+    if empty {
+        commands.entity(content_1).insert(EmptyContent);
+        commands.entity(content_2).insert(EmptyContent);
+    }
+
+    let content_limits = physics_tuning.content_limits;
     commands.spawn((
         PrismaticJoint::new(container, content_1)
             .with_free_axis(Vec2::Y)
-            .with_limits(
-                -BOTTLE_BODY_SIZE.y / 2. + CONTENT_RADIUS,
-                BOTTLE_BODY_SIZE.y / 2. - CONTENT_RADIUS + BOTTLE_NECK_HEIGHT,
-            ),
+            .with_limits(content_limits.0, content_limits.1),
         BottleContentJoint,
     ));
 
     commands.spawn((
         PrismaticJoint::new(container, content_2)
             .with_free_axis(Vec2::Y)
-            .with_limits(
-                -BOTTLE_BODY_SIZE.y / 2. + CONTENT_RADIUS,
-                BOTTLE_BODY_SIZE.y / 2. - CONTENT_RADIUS + BOTTLE_NECK_HEIGHT,
-            ),
+            .with_limits(content_limits.0, content_limits.1),
         BottleContentJoint,
     ));
+
+    // Spawned last, so bottles with a fixed cap play out as they always have.
+    if loose_cap {
+        let cap = commands
+            .spawn((
+                TransformBundle::from_transform(Transform::from_translation(
+                    (position + Vec2::Y * BOTTLE_CAP_OFFSET).extend(0.),
+                )),
+                RigidBody::Dynamic,
+                BottleCap,
+                LooseCap,
+                Grabbable,
+                ColliderDensity(physics_tuning.bottle_density),
+                Collider::rectangle(BOTTLE_CAP_SIZE.x, BOTTLE_CAP_SIZE.y),
+                CollisionLayers::new(
+                    CustomCollisionLayer::Bottle,
+                    [CustomCollisionLayer::Platform, CustomCollisionLayer::Target],
+                ),
+            ))
+            .id();
+
+        commands.spawn((
+            FixedJoint::new(container, cap).with_local_anchor_1(Vec2::Y * BOTTLE_CAP_OFFSET),
+            CapJoint,
+        ));
+    }
+
+    container
+}
+
+// Knocks a loose cap off when it takes a hard hit head on. It's just a piece
+// of plastic after that, no longer part of the bottle.
+pub fn pop_loose_caps(
+    mut commands: Commands,
+    collisions: Res<Collisions>,
+    bottle_query: Query<&Rotation, With<Bottle>>,
+    cap_query: Query<Entity, (With<LooseCap>, With<BottleCap>)>,
+    cap_joint_query: Query<Entity, With<CapJoint>>,
+    collider_query: Query<(&ColliderParent, &Rotation)>,
+    mut impact_event_reader: EventReader<Impact>,
+) {
+    let (Ok(rotation), Ok(cap)) = (bottle_query.get_single(), cap_query.get_single()) else {
+        impact_event_reader.clear();
+        return;
+    };
+
+    let up = Vec2::from_angle(rotation.as_radians()).rotate(Vec2::Y);
+    let hard_hits = impact_event_reader
+        .read()
+        .filter(|impact| impact.speed >= CAP_POP_SPEED)
+        .filter_map(|impact| impact.other(cap))
+        .filter(|other| cap_hits_head_on(&collisions, &collider_query, cap, *other, up))
+        .count();
+    if hard_hits == 0 {
+        return;
+    }
+
+    for cap_joint in &cap_joint_query {
+        commands.entity(cap_joint).despawn_recursive();
+    }
+    commands.entity(cap).remove::<(BottleCap, Grabbable)>();
+}
+
+// Whether the cap is touching `other` head on, pushed straight down the
+// bottle's `up` axis rather than grazed from the side.
+pub fn cap_hits_head_on(
+    collisions: &Collisions,
+    collider_query: &Query<(&ColliderParent, &Rotation)>,
+    cap: Entity,
+    other: Entity,
+    up: Vec2,
+) -> bool {
+    let Ok((_, cap_rotation)) = collider_query.get(cap) else {
+        return false;
+    };
+
+    collisions.collisions_with_entity(cap).any(|contacts| {
+        let (other_collider, cap_first) = if contacts.entity1 == cap {
+            (contacts.entity2, true)
+        } else {
+            (contacts.entity1, false)
+        };
+        let touches_other = collider_query
+            .get(other_collider)
+            .is_ok_and(|(parent, _)| parent.get() == other);
+
+        // Each normal points out of its own collider, towards the other one.
+        contacts.during_current_frame
+            && touches_other
+            && contacts.manifolds.iter().any(|manifold| {
+                let normal = if cap_first {
+                    manifold.global_normal1(cap_rotation)
+                } else {
+                    manifold.global_normal2(cap_rotation)
+                };
+                normal.dot(up) >= CAP_HIT_ALIGNMENT
+            })
+    })
+}
+
+// Lets the content out once an open bottle tips its neck downwards. Without
+// it, the bottle is lighter and balances differently.
+pub fn spill_content(
+    mut commands: Commands,
+    bottle_query: Query<(&Position, &Rotation), With<Bottle>>,
+    loose_cap_query: Query<(), With<LooseCap>>,
+    cap_query: Query<(), With<BottleCap>>,
+    content_joint_query: Query<Entity, With<BottleContentJoint>>,
+    content_query: Query<Entity, (With<BottleContent>, Without<Spilled>)>,
+    mut spilled_event_writer: EventWriter<ContentSpilled>,
+) {
+    if loose_cap_query.is_empty() || !cap_query.is_empty() || content_query.is_empty() {
+        return;
+    }
+
+    let Ok((position, rotation)) = bottle_query.get_single() else {
+        return;
+    };
+
+    let up = Vec2::from_angle(rotation.as_radians()).rotate(Vec2::Y);
+    if up.y > SPILL_TILT {
+        return;
+    }
+
+    for content_joint in &content_joint_query {
+        commands.entity(content_joint).despawn_recursive();
+    }
+    for content in &content_query {
+        commands.entity(content).insert((
+            Spilled,
+            // Puddles on whatever is below instead of falling through it.
+            CollisionLayers::new(
+                CustomCollisionLayer::Platform,
+                [CustomCollisionLayer::Platform],
+            ),
+        ));
+    }
+
+    spilled_event_writer.send(ContentSpilled {
+        position: position.0 + up * BOTTLE_CAP_OFFSET,
+    });
 }
 
-fn world_from_viewport(
+pub fn world_from_viewport(
     camera: &Camera,
     camera_transform: &GlobalTransform,
     position: Option<Vec2>,
@@ -141,201 +339,526 @@ fn world_from_viewport(
     camera.viewport_to_world_2d(camera_transform, viewport_position)
 }
 
-fn try_grab_bottle(
-    commands: &mut Commands,
-    anchor: Entity,
-    bottle: Entity,
-    bottle_transform: &GlobalTransform,
-    cursor_position: Vec2,
-    grabbable_transform: &GlobalTransform,
-    grabbable: &Collider,
+fn hits_bottle(
+    grabbable_query: &Query<(&GlobalTransform, &Collider), With<Grabbable>>,
+    point: Vec2,
 ) -> bool {
-    let (_scale, rotation, translation) = grabbable_transform.to_scale_rotation_translation();
-    if grabbable.contains_point(translation.xy(), rotation, cursor_position) {
-        let grabbed_at = bottle_transform
-            .affine()
-            .inverse()
-            .transform_point(cursor_position.extend(0.));
+    grabbable_query
+        .iter()
+        .any(|(grabbable_transform, collider)| {
+            let (_scale, rotation, translation) =
+                grabbable_transform.to_scale_rotation_translation();
+            collider.contains_point(translation.xy(), rotation, point)
+        })
+}
 
-        commands.spawn((
-            RevoluteJoint::new(anchor, bottle)
-                .with_local_anchor_2(grabbed_at.xy())
-                .with_angular_velocity_damping(20.),
-            GrabJoint,
-        ));
+pub fn read_mouse_grab_input(
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    windows: Query<&Window>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    mut grab_input_event_writer: EventWriter<GrabInput>,
+) {
+    let (Ok((camera, camera_transform)), Ok(window)) =
+        (camera_query.get_single(), windows.get_single())
+    else {
+        return;
+    };
+    let Some(cursor_position) =
+        world_from_viewport(camera, camera_transform, window.cursor_position())
+    else {
+        return;
+    };
 
-        return true;
+    if buttons.just_pressed(MouseButton::Left) {
+        grab_input_event_writer.send(GrabInput::Press(cursor_position));
+    } else if buttons.just_released(MouseButton::Left) {
+        grab_input_event_writer.send(GrabInput::Release(cursor_position));
+    } else if buttons.pressed(MouseButton::Left) {
+        grab_input_event_writer.send(GrabInput::Drag(cursor_position));
     }
-
-    false
 }
 
-pub fn grab_bottle_using_touch(
-    mut commands: Commands,
+pub fn read_touch_grab_input(
     camera_query: Query<(&Camera, &GlobalTransform)>,
-    anchor_query: Query<Entity, With<GrabAnchor>>,
-    bottle_query: Query<(Entity, &GlobalTransform), With<Bottle>>,
-    grabbable_query: Query<(&GlobalTransform, &Collider), With<Grabbable>>,
-    touches: Res<Touches>,
     mut touch_grab: ResMut<TouchGrab>,
+    mut touch_event_reader: EventReader<TouchInput>,
+    mut grab_input_event_writer: EventWriter<GrabInput>,
 ) {
-    if touch_grab.0.is_some() {
+    let Ok((camera, camera_transform)) = camera_query.get_single() else {
         return;
-    }
+    };
 
-    for touch in touches.iter() {
-        if touches.just_pressed(touch.id()) {
-            let (camera, camera_transform) = camera_query.single();
-            let Some(cursor_position) =
-                world_from_viewport(camera, camera_transform, Some(touch.position()))
-            else {
-                return;
-            };
-
-            for anchor in &anchor_query {
-                for (bottle, bottle_transform) in &bottle_query {
-                    for (grabbable_transform, collider) in &grabbable_query {
-                        if try_grab_bottle(
-                            &mut commands,
-                            anchor,
-                            bottle,
-                            bottle_transform,
-                            cursor_position,
-                            grabbable_transform,
-                            collider,
-                        ) {
-                            touch_grab.0 = Some(touch.id());
-                            return;
-                        }
-                    }
-                }
+    for touch_event in touch_event_reader.read() {
+        let Some(touch_position) =
+            world_from_viewport(camera, camera_transform, Some(touch_event.position))
+        else {
+            continue;
+        };
+
+        match touch_event.phase {
+            TouchPhase::Started if touch_grab.0.is_none() => {
+                touch_grab.0 = Some(touch_event.id);
+                grab_input_event_writer.send(GrabInput::Press(touch_position));
+            }
+            TouchPhase::Moved if touch_grab.0 == Some(touch_event.id) => {
+                grab_input_event_writer.send(GrabInput::Drag(touch_position));
             }
+            TouchPhase::Ended | TouchPhase::Canceled if touch_grab.0 == Some(touch_event.id) => {
+                touch_grab.0 = None;
+                grab_input_event_writer.send(GrabInput::Release(touch_position));
+            }
+            _ => {}
         }
     }
 }
 
-pub fn grab_bottle_using_mouse(
+pub fn direct_grab(
     mut commands: Commands,
-    camera_query: Query<(&Camera, &GlobalTransform)>,
-    windows: Query<&Window>,
-    anchor_query: Query<Entity, With<GrabAnchor>>,
-    bottle_query: Query<(Entity, &GlobalTransform), With<Bottle>>,
+    mut throw_limits: ResMut<ThrowLimits>,
+    mut anchor_query: Query<&mut Transform, With<GrabAnchor>>,
+    bottle_query: Query<&GlobalTransform, With<Bottle>>,
     grabbable_query: Query<(&GlobalTransform, &Collider), With<Grabbable>>,
-    buttons: Res<ButtonInput<MouseButton>>,
+    joint_query: Query<(Entity, &GrabJoint)>,
+    mut grab_input_event_reader: EventReader<GrabInput>,
+    mut thrown_event_writer: EventWriter<BottleThrown>,
 ) {
-    if buttons.just_pressed(MouseButton::Left) {
-        let (camera, camera_transform) = camera_query.single();
-        let window = windows.single();
-        let Some(cursor_position) =
-            world_from_viewport(camera, camera_transform, window.cursor_position())
-        else {
-            return;
-        };
+    for grab_input in grab_input_event_reader.read() {
+        match *grab_input {
+            GrabInput::Press(point) => {
+                if !joint_query.is_empty() || !hits_bottle(&grabbable_query, point) {
+                    continue;
+                }
+
+                let (Ok(mut anchor_transform), Ok(bottle_transform)) =
+                    (anchor_query.get_single_mut(), bottle_query.get_single())
+                else {
+                    continue;
+                };
+
+                anchor_transform.translation = point.extend(0.);
+                let grabbed_at = bottle_transform
+                    .affine()
+                    .inverse()
+                    .transform_point(point.extend(0.));
 
-        for anchor in &anchor_query {
-            for (bottle, bottle_transform) in &bottle_query {
-                for (grabbable_transform, collider) in &grabbable_query {
-                    if try_grab_bottle(
-                        &mut commands,
-                        anchor,
-                        bottle,
-                        bottle_transform,
-                        cursor_position,
-                        grabbable_transform,
-                        collider,
-                    ) {
-                        return;
+                commands.spawn(GrabJoint {
+                    local_anchor: grabbed_at.xy(),
+                    origin: point,
+                });
+                throw_limits.exceeded = false;
+            }
+            GrabInput::Drag(point) => {
+                let point = match joint_query.get_single() {
+                    Ok((_, joint)) => {
+                        joint.origin + (point - joint.origin).clamp_length_max(GRAB_REACH)
                     }
+                    Err(_) => point,
+                };
+
+                for mut anchor_transform in &mut anchor_query {
+                    anchor_transform.translation = point.extend(0.);
                 }
             }
+            GrabInput::Release(_) => {
+                if joint_query.is_empty() {
+                    continue;
+                }
+
+                for (joint, _) in &joint_query {
+                    commands.entity(joint).despawn();
+                }
+                thrown_event_writer.send(BottleThrown);
+            }
         }
     }
 }
 
-pub fn drag_bottle_using_touch(
-    camera_query: Query<(&Camera, &GlobalTransform)>,
-    mut anchor_query: Query<&mut Transform, With<GrabAnchor>>,
-    touch_grab: Res<TouchGrab>,
-    mut touch_event_reader: EventReader<TouchInput>,
+// Pulls the grabbed point towards the anchor with a bounded spring, instead of
+// pinning it there, so fast pointer movement can't yank the bottle arbitrarily.
+pub fn apply_grab_spring(
+    time: Res<Time>,
+    physics_tuning: Res<PhysicsTuning>,
+    gravity: Res<Gravity>,
+    joint_query: Query<&GrabJoint>,
+    anchor_query: Query<&Transform, With<GrabAnchor>>,
+    mut bottle_query: Query<
+        (
+            &Position,
+            &Rotation,
+            &Mass,
+            &Inertia,
+            &mut LinearVelocity,
+            &mut AngularVelocity,
+        ),
+        (With<Bottle>, Without<BottleContent>),
+    >,
+    content_query: Query<&Mass, With<BottleContent>>,
 ) {
-    if touch_grab.0.is_none() {
+    let (Ok(joint), Ok(anchor_transform)) = (joint_query.get_single(), anchor_query.get_single())
+    else {
         return;
-    }
+    };
+    let Ok((position, rotation, mass, inertia, mut linear_velocity, mut angular_velocity)) =
+        bottle_query.get_single_mut()
+    else {
+        return;
+    };
 
-    for touch_event in touch_event_reader.read() {
-        if TouchPhase::Moved == touch_event.phase {
-            if touch_grab.0 != Some(touch_event.id) {
-                continue;
-            }
+    let delta_seconds = time.delta_seconds();
+    let offset = *rotation * joint.local_anchor;
+    let grabbed_point = position.0 + offset;
+    let grabbed_velocity = linear_velocity.0 + angular_velocity.0 * offset.perp();
 
-            let (camera, camera_transform) = camera_query.single();
-            let Some(cursor_point) =
-                world_from_viewport(camera, camera_transform, Some(touch_event.position))
-            else {
-                return;
-            };
+    let acceleration = (physics_tuning.grab_stiffness
+        * (anchor_transform.translation.xy() - grabbed_point)
+        - physics_tuning.grab_damping * grabbed_velocity)
+        .clamp_length_max(physics_tuning.max_grab_acceleration);
 
-            for mut anchor_transform in &mut anchor_query {
-                anchor_transform.translation = cursor_point.extend(0.);
-            }
+    // Hold up the weight of the contents hanging in the bottle as well.
+    let total_mass = mass.0 + content_query.iter().map(|mass| mass.0).sum::<f32>();
 
-            return;
+    linear_velocity.0 += (acceleration - gravity.0 * total_mass / mass.0) * delta_seconds;
+    angular_velocity.0 += mass.0 * offset.perp_dot(acceleration) / inertia.0 * delta_seconds;
+    angular_velocity.0 /= 1. + physics_tuning.grab_angular_damping * delta_seconds;
+}
+
+pub fn cap_velocities(
+    physics_tuning: Res<PhysicsTuning>,
+    mut throw_limits: ResMut<ThrowLimits>,
+    joint_query: Query<(), With<GrabJoint>>,
+    mut body_query: Query<
+        (&mut LinearVelocity, &mut AngularVelocity),
+        Or<(With<Bottle>, With<BottleContent>, With<LooseCap>)>,
+    >,
+) {
+    let mut capped = false;
+
+    for (mut linear_velocity, mut angular_velocity) in &mut body_query {
+        if linear_velocity.0.length() > physics_tuning.max_linear_speed {
+            linear_velocity.0 = linear_velocity
+                .0
+                .clamp_length_max(physics_tuning.max_linear_speed);
+            capped = true;
         }
+
+        if angular_velocity.0.abs() > physics_tuning.max_angular_speed {
+            angular_velocity.0 = angular_velocity.0.clamp(
+                -physics_tuning.max_angular_speed,
+                physics_tuning.max_angular_speed,
+            );
+            capped = true;
+        }
+    }
+
+    if capped && !joint_query.is_empty() {
+        throw_limits.exceeded = true;
     }
 }
 
-pub fn drag_bottle_using_mouse(
-    camera_query: Query<(&Camera, &GlobalTransform)>,
-    windows: Query<&Window>,
-    mut anchor_query: Query<&mut Transform, With<GrabAnchor>>,
+pub fn check_throw_plausibility(
+    mut throw_limits: ResMut<ThrowLimits>,
+    bottle_query: Query<(&LinearVelocity, &AngularVelocity), With<Bottle>>,
+    mut thrown_event_reader: EventReader<BottleThrown>,
+    mut implausible_event_writer: EventWriter<ImplausibleThrow>,
 ) {
-    let (camera, camera_transform) = camera_query.single();
-    let window = windows.single();
-    let Some(cursor_point) =
-        world_from_viewport(camera, camera_transform, window.cursor_position())
+    if thrown_event_reader.read().count() == 0 {
+        return;
+    }
+
+    let Ok((linear_velocity, angular_velocity)) = bottle_query.get_single() else {
+        return;
+    };
+
+    if throw_limits.exceeded
+        || linear_velocity.0.length() > PLAUSIBLE_LINEAR_SPEED
+        || angular_velocity.0.abs() > PLAUSIBLE_ANGULAR_SPEED
+    {
+        implausible_event_writer.send(ImplausibleThrow);
+    }
+
+    throw_limits.exceeded = false;
+}
+
+fn slingshot_velocity(aiming: &Aiming) -> Vec2 {
+    (aiming.start - aiming.current).clamp_length_max(SLINGSHOT_MAX_PULL) * SLINGSHOT_STRENGTH
+}
+
+pub fn slingshot_spin(linear_velocity: Vec2) -> f32 {
+    -linear_velocity.x * SLINGSHOT_SPIN
+}
+
+// Swiping past the grabbed point's lever arm spins the bottle.
+pub fn flick_spin(grabbed_at: Vec2, linear_velocity: Vec2) -> f32 {
+    grabbed_at.perp_dot(linear_velocity) * FLICK_SPIN
+}
+
+// Average pointer velocity over the last moments of the swipe.
+fn flick_velocity(aiming: &Aiming) -> Vec2 {
+    let (Some((first_time, first_point)), Some((last_time, last_point))) =
+        (aiming.samples.front(), aiming.samples.back())
+    else {
+        return Vec2::ZERO;
+    };
+
+    let duration = last_time - first_time;
+    if duration <= 0. {
+        return Vec2::ZERO;
+    }
+
+    ((*last_point - *first_point) / duration).clamp_length_max(FLICK_MAX_SPEED)
+}
+
+fn launch_bottle(
+    bottle_query: &mut Query<
+        (&Position, &mut LinearVelocity, &mut AngularVelocity),
+        (With<Bottle>, Without<BottleContent>),
+    >,
+    content_query: &mut Query<(&Position, &mut LinearVelocity), With<BottleContent>>,
+    linear_velocity: Vec2,
+    angular_velocity: f32,
+) {
+    let Ok((bottle_position, mut bottle_linear_velocity, mut bottle_angular_velocity)) =
+        bottle_query.get_single_mut()
     else {
         return;
     };
 
-    for mut anchor_transform in &mut anchor_query {
-        anchor_transform.translation = cursor_point.extend(0.);
+    bottle_linear_velocity.0 = linear_velocity;
+    bottle_angular_velocity.0 = angular_velocity;
+
+    // Contents move along with the spinning bottle.
+    for (content_position, mut content_linear_velocity) in content_query {
+        let offset = content_position.0 - bottle_position.0;
+        content_linear_velocity.0 = linear_velocity + angular_velocity * offset.perp();
     }
 }
 
-pub fn release_bottle_using_touch(
-    mut commands: Commands,
-    joint_query: Query<Entity, With<GrabJoint>>,
-    mut touch_grab: ResMut<TouchGrab>,
-    mut touch_event_reader: EventReader<TouchInput>,
+fn start_aiming(
+    aim: &mut Aim,
+    bottle_query: &Query<
+        (&Position, &mut LinearVelocity, &mut AngularVelocity),
+        (With<Bottle>, Without<BottleContent>),
+    >,
+    grabbable_query: &Query<(&GlobalTransform, &Collider), With<Grabbable>>,
+    time: f32,
+    point: Vec2,
 ) {
-    if touch_grab.0.is_none() {
+    if aim.0.is_some() || !hits_bottle(grabbable_query, point) {
         return;
     }
 
-    for touch_event in touch_event_reader.read() {
-        if touch_event.phase == TouchPhase::Ended || touch_event.phase == TouchPhase::Canceled {
-            if touch_grab.0 != Some(touch_event.id) {
-                continue;
+    let Ok((bottle_position, _, _)) = bottle_query.get_single() else {
+        return;
+    };
+
+    aim.0 = Some(Aiming {
+        start: point,
+        current: point,
+        offset: point - bottle_position.0,
+        samples: [(time, point)].into(),
+    });
+}
+
+pub fn slingshot(
+    mut aim: ResMut<Aim>,
+    time: Res<Time>,
+    mut bottle_query: Query<
+        (&Position, &mut LinearVelocity, &mut AngularVelocity),
+        (With<Bottle>, Without<BottleContent>),
+    >,
+    mut content_query: Query<(&Position, &mut LinearVelocity), With<BottleContent>>,
+    grabbable_query: Query<(&GlobalTransform, &Collider), With<Grabbable>>,
+    mut grab_input_event_reader: EventReader<GrabInput>,
+    mut thrown_event_writer: EventWriter<BottleThrown>,
+) {
+    for grab_input in grab_input_event_reader.read() {
+        match *grab_input {
+            GrabInput::Press(point) => {
+                start_aiming(
+                    &mut aim,
+                    &bottle_query,
+                    &grabbable_query,
+                    time.elapsed_seconds(),
+                    point,
+                );
+            }
+            GrabInput::Drag(point) => {
+                if let Some(aiming) = &mut aim.0 {
+                    aiming.current = point;
+                }
             }
+            GrabInput::Release(point) => {
+                let Some(mut aiming) = aim.0.take() else {
+                    continue;
+                };
+                aiming.current = point;
+
+                let linear_velocity = slingshot_velocity(&aiming);
+                launch_bottle(
+                    &mut bottle_query,
+                    &mut content_query,
+                    linear_velocity,
+                    slingshot_spin(linear_velocity),
+                );
+                thrown_event_writer.send(BottleThrown);
+            }
+        }
+    }
+}
+
+pub fn flick(
+    mut aim: ResMut<Aim>,
+    time: Res<Time>,
+    mut bottle_query: Query<
+        (&Position, &mut LinearVelocity, &mut AngularVelocity),
+        (With<Bottle>, Without<BottleContent>),
+    >,
+    mut content_query: Query<(&Position, &mut LinearVelocity), With<BottleContent>>,
+    grabbable_query: Query<(&GlobalTransform, &Collider), With<Grabbable>>,
+    mut grab_input_event_reader: EventReader<GrabInput>,
+    mut thrown_event_writer: EventWriter<BottleThrown>,
+) {
+    let now = time.elapsed_seconds();
 
-            for joint in &joint_query {
-                commands.entity(joint).despawn();
+    for grab_input in grab_input_event_reader.read() {
+        match *grab_input {
+            GrabInput::Press(point) => {
+                start_aiming(&mut aim, &bottle_query, &grabbable_query, now, point);
             }
+            GrabInput::Drag(point) | GrabInput::Release(point) => {
+                let Some(aiming) = &mut aim.0 else {
+                    continue;
+                };
 
-            touch_grab.0 = None;
-            return;
+                aiming.current = point;
+                aiming.samples.push_back((now, point));
+                while aiming
+                    .samples
+                    .front()
+                    .is_some_and(|(sample_time, _)| now - sample_time > FLICK_WINDOW)
+                {
+                    aiming.samples.pop_front();
+                }
+
+                if !matches!(grab_input, GrabInput::Release(_)) {
+                    continue;
+                }
+
+                let Some(aiming) = aim.0.take() else {
+                    continue;
+                };
+
+                let linear_velocity = flick_velocity(&aiming);
+                launch_bottle(
+                    &mut bottle_query,
+                    &mut content_query,
+                    linear_velocity,
+                    flick_spin(aiming.offset, linear_velocity),
+                );
+                thrown_event_writer.send(BottleThrown);
+            }
         }
     }
 }
 
-pub fn release_bottle_using_mouse(
-    mut commands: Commands,
-    joint_query: Query<Entity, With<GrabJoint>>,
-    buttons: Res<ButtonInput<MouseButton>>,
+pub fn detect_bottle_landing(
+    time: Res<Time>,
+    mut flight: ResMut<Flight>,
+    aim: Res<Aim>,
+    mut thrown_event_reader: EventReader<BottleThrown>,
+    joint_query: Query<(), With<GrabJoint>>,
+    bottle_query: Query<(&Rotation, &LinearVelocity, &AngularVelocity), With<Bottle>>,
+    spilled_query: Query<(), With<Spilled>>,
+    mut landed_event_writer: EventWriter<BottleLanded>,
 ) {
-    if buttons.just_released(MouseButton::Left) {
-        for joint in &joint_query {
-            commands.entity(joint).despawn();
+    if thrown_event_reader.read().count() > 0 {
+        flight.airborne = true;
+        flight.resting_time = 0.;
+    }
+
+    if !joint_query.is_empty() || aim.0.is_some() {
+        flight.airborne = false;
+        return;
+    }
+
+    if !flight.airborne {
+        return;
+    }
+
+    let Ok((rotation, linear_velocity, angular_velocity)) = bottle_query.get_single() else {
+        return;
+    };
+
+    if linear_velocity.0.length() > RESTING_LINEAR_SPEED
+        || angular_velocity.0.abs() > RESTING_ANGULAR_SPEED
+    {
+        flight.resting_time = 0.;
+        return;
+    }
+
+    flight.resting_time += time.delta_seconds();
+    if flight.resting_time >= RESTING_DURATION {
+        flight.airborne = false;
+        let angle = rotation.as_radians().abs();
+        landed_event_writer.send(BottleLanded {
+            upright: angle < UPRIGHT_TOLERANCE,
+            upside_down: angle > PI - UPRIGHT_TOLERANCE,
+            spilled: !spilled_query.is_empty(),
+        });
+    }
+}
+
+pub fn draw_aim(
+    settings: Res<Settings>,
+    throw_mode: Res<ThrowMode>,
+    aim: Res<Aim>,
+    mut gizmos: Gizmos,
+) {
+    let Some(aiming) = &aim.0 else {
+        return;
+    };
+
+    let color = settings.palette.hint();
+    match *throw_mode {
+        ThrowMode::Slingshot => {
+            let pull = (aiming.current - aiming.start).clamp_length_max(SLINGSHOT_MAX_PULL);
+            gizmos.line_2d(aiming.start, aiming.start + pull, color);
+        }
+        ThrowMode::Flick => {
+            gizmos.linestrip_2d(aiming.samples.iter().map(|(_, point)| *point), color);
         }
+        ThrowMode::DirectGrab => {}
+    }
+}
+
+pub fn draw_trajectory_preview(
+    settings: Res<Settings>,
+    gravity: Res<Gravity>,
+    throw_mode: Res<ThrowMode>,
+    aim: Res<Aim>,
+    joint_query: Query<(), With<GrabJoint>>,
+    bottle_query: Query<(&Position, &LinearVelocity), With<Bottle>>,
+    mut gizmos: Gizmos,
+) {
+    if !settings.trajectory_preview {
+        return;
+    }
+
+    let Ok((position, linear_velocity)) = bottle_query.get_single() else {
+        return;
+    };
+
+    let launch_velocity = match (*throw_mode, &aim.0) {
+        (ThrowMode::DirectGrab, _) if !joint_query.is_empty() => linear_velocity.0,
+        (ThrowMode::Slingshot, Some(aiming)) => slingshot_velocity(aiming),
+        _ => return,
+    };
+
+    // Where the bottle would fly if it was released right now, ignoring collisions.
+    let color = settings.palette.hint().with_alpha(0.6);
+    for step in 0..TRAJECTORY_STEPS {
+        let t = TRAJECTORY_DURATION * step as f32 / TRAJECTORY_STEPS as f32;
+        let point = position.0 + launch_velocity * t + 0.5 * gravity.0 * t * t;
+        gizmos.circle_2d(point, 3., color);
     }
 }
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BreakMaterial {
+    Cardboard,
+    Glass,
+    Ceramic,
+}
+
+impl BreakMaterial {
+    pub fn fragment_color(self) -> Color {
+        match self {
+            BreakMaterial::Cardboard => Color::srgb(0.714, 0.482, 0.29),
+            BreakMaterial::Glass => Color::srgba(0.702, 0.878, 0.918, 0.8),
+            BreakMaterial::Ceramic => Color::srgb(0.961, 0.953, 0.929),
+        }
+    }
+}
+
+// A prop that takes damage from impacts faster than `threshold`, and breaks
+// into fragments once its health runs out.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Breakable {
+    pub material: BreakMaterial,
+    pub threshold: f32,
+    pub health: u32,
+    // Size of the unscaled prop, for cutting it into fragments.
+    pub size: Vec2,
+}
+
+// A bottle that shatters when it comes down hard on its cap.
+#[derive(Component)]
+pub struct GlassBottle;
+
+// A piece of a broken prop, tidied away after a while.
+#[derive(Component)]
+pub struct Fragment {
+    pub timer: Timer,
+}
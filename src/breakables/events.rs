@@ -0,0 +1,13 @@
+use crate::breakables::components::BreakMaterial;
+use bevy::prelude::*;
+
+// A prop was hit hard enough to take damage, and whether that broke it.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PropDamaged {
+    pub material: BreakMaterial,
+    pub position: Vec2,
+    pub broken: bool,
+}
+
+#[derive(Event)]
+pub struct BottleShattered;
@@ -0,0 +1,31 @@
+pub mod components;
+pub mod events;
+mod systems;
+
+use crate::breakables::events::{BottleShattered, PropDamaged};
+use crate::breakables::systems::{
+    damage_props, expire_fragments, shatter_glass_bottles, tint_glass_bottles,
+};
+use crate::game::GameState;
+use crate::physics::ImpactDetection;
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+// Boxes that crumple and tear, plates that smash and glass bottles that
+// shatter when they land on their cap.
+pub struct BreakablesPlugin;
+
+impl Plugin for BreakablesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PropDamaged>();
+        app.add_event::<BottleShattered>();
+
+        app.add_systems(Update, (tint_glass_bottles, expire_fragments));
+        app.add_systems(
+            PhysicsSchedule,
+            (damage_props, shatter_glass_bottles)
+                .after(ImpactDetection)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use crate::bottle::components::{
+    Bottle, BottleCap, BottleContent, BottleContentJoint, CapJoint, GrabJoint, LooseCap,
+};
+use crate::bottle::systems::{cap_hits_head_on, BOTTLE_BODY_SIZE};
+use crate::breakables::components::{BreakMaterial, Breakable, Fragment, GlassBottle};
+use crate::breakables::events::{BottleShattered, PropDamaged};
+use crate::physics::events::Impact;
+use crate::physics::CustomCollisionLayer;
+use crate::random::Rng;
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+// Each hit squashes a box this much more before it tears.
+const CRUMPLE_SCALE: f32 = 0.85;
+// Columns and rows a prop is cut into when it breaks.
+const FRAGMENT_GRID: UVec2 = UVec2::new(3, 2);
+const FRAGMENT_SPEED: f32 = 250.;
+const FRAGMENT_SPIN: f32 = 8.;
+const FRAGMENT_LIFETIME: f32 = 3.;
+const FRAGMENT_Z: f32 = 1.;
+const GLASS_SHATTER_SPEED: f32 = 700.;
+const GLASS_TINT: Color = Color::srgba(0.75, 0.93, 0.96, 0.85);
+
+// Cuts a prop into pieces flying apart from where it was. Seeded by where the
+// break happened, so a replayed throw breaks things the same way.
+fn spawn_fragments(
+    commands: &mut Commands,
+    material: BreakMaterial,
+    size: Vec2,
+    position: Vec2,
+    rotation: f32,
+    linear_velocity: Vec2,
+) {
+    let seed = (u64::from(position.x.to_bits()) << 32) | u64::from(position.y.to_bits());
+    let mut rng = Rng::new(seed);
+    let piece = size / FRAGMENT_GRID.as_vec2();
+
+    for column in 0..FRAGMENT_GRID.x {
+        for row in 0..FRAGMENT_GRID.y {
+            let local = (UVec2::new(column, row).as_vec2() + 0.5) * piece - size / 2.;
+            let offset = Vec2::from_angle(rotation).rotate(local);
+            let velocity =
+                linear_velocity + offset.normalize_or_zero() * FRAGMENT_SPEED * rng.range(0.5, 1.);
+
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: material.fragment_color(),
+                        custom_size: Some(piece * 0.9),
+                        ..default()
+                    },
+                    transform: Transform::from_translation((position + offset).extend(FRAGMENT_Z))
+                        .with_rotation(Quat::from_rotation_z(rotation)),
+                    ..default()
+                },
+                RigidBody::Dynamic,
+                Collider::rectangle(piece.x * 0.9, piece.y * 0.9),
+                // Pieces fall onto platforms without getting in the bottle's way.
+                CollisionLayers::new(
+                    CustomCollisionLayer::Platform,
+                    [CustomCollisionLayer::Platform],
+                ),
+                LinearVelocity(velocity),
+                AngularVelocity(rng.range(-FRAGMENT_SPIN, FRAGMENT_SPIN)),
+                Fragment {
+                    timer: Timer::from_seconds(FRAGMENT_LIFETIME, TimerMode::Once),
+                },
+            ));
+        }
+    }
+}
+
+pub fn damage_props(
+    mut commands: Commands,
+    mut breakable_query: Query<(
+        &mut Breakable,
+        &mut Transform,
+        &Position,
+        &Rotation,
+        &LinearVelocity,
+    )>,
+    mut impact_event_reader: EventReader<Impact>,
+    mut damaged_event_writer: EventWriter<PropDamaged>,
+) {
+    for impact in impact_event_reader.read() {
+        for body in [impact.body_1, impact.body_2] {
+            let Ok((mut breakable, mut transform, position, rotation, linear_velocity)) =
+                breakable_query.get_mut(body)
+            else {
+                continue;
+            };
+
+            if breakable.health == 0 || impact.speed < breakable.threshold {
+                continue;
+            }
+
+            breakable.health -= 1;
+            let broken = breakable.health == 0;
+            if broken {
+                commands.entity(body).despawn_recursive();
+                spawn_fragments(
+                    &mut commands,
+                    breakable.material,
+                    breakable.size * transform.scale.truncate(),
+                    position.0,
+                    rotation.as_radians(),
+                    linear_velocity.0,
+                );
+            } else if breakable.material == BreakMaterial::Cardboard {
+                transform.scale.y *= CRUMPLE_SCALE;
+            }
+
+            damaged_event_writer.send(PropDamaged {
+                material: breakable.material,
+                position: impact.point,
+                broken,
+            });
+        }
+    }
+}
+
+pub fn shatter_glass_bottles(
+    mut commands: Commands,
+    collisions: Res<Collisions>,
+    bottle_query: Query<(Entity, &Position, &Rotation, &LinearVelocity), With<GlassBottle>>,
+    cap_query: Query<Entity, With<BottleCap>>,
+    collider_query: Query<(&ColliderParent, &Rotation)>,
+    bottle_part_query: Query<
+        Entity,
+        Or<(
+            With<Bottle>,
+            With<BottleContent>,
+            With<BottleContentJoint>,
+            With<GrabJoint>,
+            With<LooseCap>,
+            With<CapJoint>,
+        )>,
+    >,
+    mut impact_event_reader: EventReader<Impact>,
+    mut damaged_event_writer: EventWriter<PropDamaged>,
+    mut shattered_event_writer: EventWriter<BottleShattered>,
+) {
+    let (Ok((bottle, position, rotation, linear_velocity)), Ok(cap)) =
+        (bottle_query.get_single(), cap_query.get_single())
+    else {
+        impact_event_reader.clear();
+        return;
+    };
+
+    let up = Vec2::from_angle(rotation.as_radians()).rotate(Vec2::Y);
+    let mut cap_first = false;
+    for impact in impact_event_reader.read() {
+        let Some(other) = impact.other(bottle) else {
+            continue;
+        };
+
+        cap_first |= impact.speed >= GLASS_SHATTER_SPEED
+            && cap_hits_head_on(&collisions, &collider_query, cap, other, up);
+    }
+
+    if !cap_first {
+        return;
+    }
+
+    for part in &bottle_part_query {
+        commands.entity(part).despawn_recursive();
+    }
+    spawn_fragments(
+        &mut commands,
+        BreakMaterial::Glass,
+        BOTTLE_BODY_SIZE,
+        position.0,
+        rotation.as_radians(),
+        linear_velocity.0,
+    );
+
+    damaged_event_writer.send(PropDamaged {
+        material: BreakMaterial::Glass,
+        position: position.0,
+        broken: true,
+    });
+    shattered_event_writer.send(BottleShattered);
+}
+
+pub fn tint_glass_bottles(
+    bottle_query: Query<&Children, Added<GlassBottle>>,
+    mut sprite_query: Query<&mut Sprite>,
+) {
+    for children in &bottle_query {
+        for child in children {
+            if let Ok(mut sprite) = sprite_query.get_mut(*child) {
+                sprite.color = GLASS_TINT;
+            }
+        }
+    }
+}
+
+pub fn expire_fragments(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut fragment_query: Query<(Entity, &mut Fragment)>,
+) {
+    for (entity, mut fragment) in &mut fragment_query {
+        if fragment.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
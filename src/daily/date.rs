@@ -0,0 +1,92 @@
+use std::fmt;
+use std::str::FromStr;
+
+// A calendar date in UTC, so everyone gets the same daily challenge whatever
+// their time zone.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Date {
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    // Days since 1970-01-01, converted with the algorithm from
+    // https://howardhinnant.github.io/date_algorithms.html.
+    pub fn from_days(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+
+        Self {
+            year: year_of_era + era * 400 + i64::from(month <= 2),
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+
+    pub fn days(self) -> i64 {
+        let year = self.year - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let shifted_month = i64::from((self.month + 9) % 12);
+        let day_of_year = (153 * shifted_month + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn today() -> Self {
+        let seconds = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+        Self::from_days((seconds / 86_400) as i64)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn today() -> Self {
+        Self::from_days((js_sys::Date::now() / 86_400_000.).floor() as i64)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{:04}-{:02}-{:02}",
+            self.year, self.month, self.day
+        )
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, String> {
+        let invalid = || format!("invalid date {value}");
+        let mut parts = value.splitn(3, '-');
+        let mut part = || parts.next().ok_or_else(invalid);
+        let year = part()?.parse().map_err(|_| invalid())?;
+        let month = part()?.parse().map_err(|_| invalid())?;
+        let day = part()?.parse().map_err(|_| invalid())?;
+
+        let date = Self { year, month, day };
+        // Catches days past the end of the month, like 2025-02-30.
+        if Self::from_days(date.days()) != date {
+            return Err(invalid());
+        }
+
+        Ok(date)
+    }
+}
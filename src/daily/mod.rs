@@ -0,0 +1,62 @@
+pub mod date;
+pub mod resources;
+#[cfg(not(target_arch = "wasm32"))]
+mod systems;
+
+use crate::daily::date::Date;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::daily::resources::DailyLevelTask;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::daily::systems::{add_daily_level, start_daily_level_task};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::game::GameState;
+use crate::levels::assets::Level;
+use crate::levels::generator::generate_level;
+use crate::random::Rng;
+use crate::solver::search::{solve, SolverConfig, Target};
+use bevy::prelude::*;
+
+// Daily levels are listed under paths like "daily/2026-10-19".
+pub const DAILY_PREFIX: &str = "daily/";
+// Seeds to try before settling for an empty level.
+const MAX_GENERATION_ATTEMPTS: u64 = 8;
+
+// The same for everyone on a given date: generated levels are tried in turn,
+// and the first one the solver can beat is the day's challenge.
+pub fn daily_level(date: Date) -> Level {
+    let name = format!("Daily {date}");
+    let seed = Rng::new(date.days() as u64).next_u64();
+
+    for attempt in 0..MAX_GENERATION_ATTEMPTS {
+        let level = generate_level(name.clone(), seed.wrapping_add(attempt));
+        let config = SolverConfig { seed, ..default() };
+        if !solve(&level, Target::AnyPlatform, &config).is_empty() {
+            return level;
+        }
+    }
+
+    // Any upright landing counts when there are no platforms.
+    Level {
+        platforms: Vec::new(),
+        ..generate_level(name, seed)
+    }
+}
+
+// Checking a level takes seconds of solving, which web builds would have to do
+// on the main thread, so the daily challenge is only offered natively.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct DailyPlugin;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Plugin for DailyPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(DailyLevelTask::default());
+
+        app.add_systems(Startup, start_daily_level_task);
+        // Waits for the campaign, which the daily level is listed after.
+        app.add_systems(
+            Update,
+            add_daily_level.run_if(not(in_state(GameState::Loading))),
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use crate::levels::assets::Level;
+use bevy::prelude::*;
+use bevy::tasks::Task;
+use serde::{Deserialize, Serialize};
+
+// Today's level, kept so it's only generated and checked once a day.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DailyLevel {
+    pub date: String,
+    pub level: Level,
+}
+
+#[derive(Resource, Default)]
+pub struct DailyLevelTask(pub Option<Task<DailyLevel>>);
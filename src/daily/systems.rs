@@ -0,0 +1,47 @@
+use crate::daily::date::Date;
+use crate::daily::resources::{DailyLevel, DailyLevelTask};
+use crate::daily::{daily_level, DAILY_PREFIX};
+use crate::levels::assets::Level;
+use crate::levels::resources::Levels;
+use crate::storage;
+use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool};
+
+const DAILY_KEY: &str = "daily_level";
+
+pub fn start_daily_level_task(mut daily_level_task: ResMut<DailyLevelTask>) {
+    let today = Date::today();
+    let date = today.to_string();
+    let cached =
+        storage::load_ron::<DailyLevel>(DAILY_KEY).filter(|daily_level| daily_level.date == date);
+
+    // Checking that a level can be beaten takes a while, so it happens off the
+    // main thread.
+    daily_level_task.0 = Some(AsyncComputeTaskPool::get().spawn(async move {
+        cached.unwrap_or_else(|| DailyLevel {
+            level: daily_level(today),
+            date,
+        })
+    }));
+}
+
+// Adds today's level after the campaign once it's ready.
+pub fn add_daily_level(
+    mut daily_level_task: ResMut<DailyLevelTask>,
+    mut level_assets: ResMut<Assets<Level>>,
+    mut levels: ResMut<Levels>,
+) {
+    let Some(task) = &mut daily_level_task.0 else {
+        return;
+    };
+
+    let Some(daily) = block_on(future::poll_once(task)) else {
+        return;
+    };
+
+    daily_level_task.0 = None;
+    storage::save_ron(DAILY_KEY, &daily);
+
+    levels.paths.push(format!("{DAILY_PREFIX}{}", daily.date));
+    levels.handles.push(level_assets.add(daily.level));
+}
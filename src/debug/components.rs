@@ -0,0 +1,107 @@
+use crate::tuning::assets::PhysicsTuning;
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct InspectorPanel;
+
+#[derive(Component)]
+pub struct InspectorText;
+
+#[derive(Component)]
+pub struct TuningPanel;
+
+#[derive(Component)]
+pub struct TuningSaveButton;
+
+#[derive(Component, Clone, Copy)]
+pub enum TuningField {
+    Gravity,
+    SubstepCount,
+    BottleDensity,
+    ContentDensity,
+    BottleAngularDamping,
+    GrabAngularDamping,
+    ContentLimitMin,
+    ContentLimitMax,
+}
+
+impl TuningField {
+    pub const ALL: [TuningField; 8] = [
+        TuningField::Gravity,
+        TuningField::SubstepCount,
+        TuningField::BottleDensity,
+        TuningField::ContentDensity,
+        TuningField::BottleAngularDamping,
+        TuningField::GrabAngularDamping,
+        TuningField::ContentLimitMin,
+        TuningField::ContentLimitMax,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            TuningField::Gravity => "Gravity",
+            TuningField::SubstepCount => "Substeps",
+            TuningField::BottleDensity => "Bottle density",
+            TuningField::ContentDensity => "Content density",
+            TuningField::BottleAngularDamping => "Angular damping",
+            TuningField::GrabAngularDamping => "Grab damping",
+            TuningField::ContentLimitMin => "Content min",
+            TuningField::ContentLimitMax => "Content max",
+        }
+    }
+
+    pub fn range(self) -> (f32, f32) {
+        match self {
+            TuningField::Gravity => (0., 6000.),
+            TuningField::SubstepCount => (1., 20.),
+            TuningField::BottleDensity => (0.05, 4.),
+            TuningField::ContentDensity => (0.1, 20.),
+            TuningField::BottleAngularDamping => (0., 5.),
+            TuningField::GrabAngularDamping => (0., 60.),
+            TuningField::ContentLimitMin => (-60., 0.),
+            TuningField::ContentLimitMax => (0., 90.),
+        }
+    }
+
+    pub fn get(self, physics_tuning: &PhysicsTuning) -> f32 {
+        match self {
+            TuningField::Gravity => physics_tuning.gravity,
+            TuningField::SubstepCount => physics_tuning.substep_count as f32,
+            TuningField::BottleDensity => physics_tuning.bottle_density,
+            TuningField::ContentDensity => physics_tuning.content_density,
+            TuningField::BottleAngularDamping => physics_tuning.bottle_angular_damping,
+            TuningField::GrabAngularDamping => physics_tuning.grab_angular_damping,
+            TuningField::ContentLimitMin => physics_tuning.content_limits.0,
+            TuningField::ContentLimitMax => physics_tuning.content_limits.1,
+        }
+    }
+
+    pub fn set(self, physics_tuning: &mut PhysicsTuning, value: f32) {
+        match self {
+            TuningField::Gravity => physics_tuning.gravity = value,
+            TuningField::SubstepCount => physics_tuning.substep_count = value.round() as u32,
+            TuningField::BottleDensity => physics_tuning.bottle_density = value,
+            TuningField::ContentDensity => physics_tuning.content_density = value,
+            TuningField::BottleAngularDamping => physics_tuning.bottle_angular_damping = value,
+            TuningField::GrabAngularDamping => physics_tuning.grab_angular_damping = value,
+            TuningField::ContentLimitMin => physics_tuning.content_limits.0 = value,
+            TuningField::ContentLimitMax => physics_tuning.content_limits.1 = value,
+        }
+    }
+
+    pub fn to_slider(self, value: f32) -> f32 {
+        let (min, max) = self.range();
+        ((value - min) / (max - min)).clamp(0., 1.)
+    }
+
+    pub fn from_slider(self, value: f32) -> f32 {
+        let (min, max) = self.range();
+        min + (max - min) * value
+    }
+}
+
+#[derive(Component)]
+pub struct TuningSlider(pub TuningField);
+
+#[derive(Component)]
+pub struct TuningValue(pub TuningField);
@@ -0,0 +1,50 @@
+mod components;
+mod systems;
+
+use crate::debug::systems::{
+    apply_physics_debug, apply_tuning_sliders, configure_physics_gizmos, draw_centers_of_mass,
+    draw_velocities, handle_tuning_save_button, spawn_inspector, spawn_tuning_panel,
+    sync_tuning_panel, toggle_physics_debug, toggle_tuning_panel, update_inspector,
+};
+use crate::settings::resources::Settings;
+use crate::tuning::assets::PhysicsTuning;
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+// Only compiled with the `debug` cargo feature, see `Cargo.toml`.
+pub struct DebugPlugin;
+
+impl Plugin for DebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(PhysicsDebugPlugin::default());
+
+        app.add_systems(
+            Startup,
+            (
+                configure_physics_gizmos,
+                spawn_inspector,
+                spawn_tuning_panel,
+            ),
+        );
+        app.add_systems(
+            Update,
+            (
+                toggle_physics_debug,
+                apply_physics_debug.run_if(resource_changed::<Settings>),
+                (draw_velocities, draw_centers_of_mass, update_inspector)
+                    .run_if(|settings: Res<Settings>| settings.physics_debug),
+            )
+                .chain(),
+        );
+        app.add_systems(
+            Update,
+            (
+                toggle_tuning_panel,
+                apply_tuning_sliders,
+                sync_tuning_panel.run_if(resource_changed::<PhysicsTuning>),
+                handle_tuning_save_button,
+            )
+                .chain(),
+        );
+    }
+}
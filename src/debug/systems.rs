@@ -0,0 +1,256 @@
+use crate::bottle::components::{Bottle, BottleContent};
+use crate::debug::components::{
+    InspectorPanel, InspectorText, TuningField, TuningPanel, TuningSaveButton, TuningSlider,
+    TuningValue,
+};
+use crate::settings::resources::Settings;
+use crate::tuning::assets::PhysicsTuning;
+use crate::tuning::systems::save_physics_tuning;
+use crate::ui::components::Slider;
+use crate::ui::events::ButtonActivated;
+use crate::ui::widgets::{spawn_menu_button, spawn_row, spawn_slider, text_style, PANEL_COLOR};
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use std::fmt::Write;
+
+const TOGGLE_KEY: KeyCode = KeyCode::F3;
+const TUNING_TOGGLE_KEY: KeyCode = KeyCode::F4;
+const INSPECTOR_FONT_SIZE: f32 = 14.;
+const VELOCITY_SCALE: f32 = 0.1;
+const VELOCITY_COLOR: Color = Color::srgb(0.2, 0.8, 1.);
+const CENTER_OF_MASS_COLOR: Color = Color::srgb(1., 0.3, 0.3);
+
+pub fn configure_physics_gizmos(mut config_store: ResMut<GizmoConfigStore>) {
+    let (_, physics_gizmos) = config_store.config_mut::<PhysicsGizmos>();
+    physics_gizmos.contact_point_color = Some(Color::srgb(1., 0.9, 0.2));
+    physics_gizmos.contact_normal_color = Some(Color::srgb(1., 0.6, 0.2));
+    physics_gizmos.joint_anchor_color = Some(Color::srgb(0.3, 1., 0.4));
+    physics_gizmos.joint_separation_color = Some(Color::srgb(1., 0.2, 0.8));
+}
+
+pub fn toggle_physics_debug(keys: Res<ButtonInput<KeyCode>>, mut settings: ResMut<Settings>) {
+    if keys.just_pressed(TOGGLE_KEY) {
+        settings.physics_debug = !settings.physics_debug;
+    }
+}
+
+pub fn apply_physics_debug(
+    settings: Res<Settings>,
+    mut config_store: ResMut<GizmoConfigStore>,
+    mut panel_query: Query<&mut Visibility, With<InspectorPanel>>,
+) {
+    let (config, _) = config_store.config_mut::<PhysicsGizmos>();
+    config.enabled = settings.physics_debug;
+
+    for mut visibility in &mut panel_query {
+        *visibility = if settings.physics_debug {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+pub fn draw_velocities(
+    body_query: Query<(&Position, &LinearVelocity), With<RigidBody>>,
+    mut gizmos: Gizmos,
+) {
+    for (position, linear_velocity) in &body_query {
+        if linear_velocity.0 != Vec2::ZERO {
+            gizmos.arrow_2d(
+                position.0,
+                position.0 + linear_velocity.0 * VELOCITY_SCALE,
+                VELOCITY_COLOR,
+            );
+        }
+    }
+}
+
+pub fn draw_centers_of_mass(
+    body_query: Query<(&Position, &Rotation, &CenterOfMass), With<RigidBody>>,
+    mut gizmos: Gizmos,
+) {
+    for (position, rotation, center_of_mass) in &body_query {
+        let center = position.0 + *rotation * center_of_mass.0;
+        gizmos.circle_2d(center, 4., CENTER_OF_MASS_COLOR);
+        gizmos.line_2d(
+            center - Vec2::X * 6.,
+            center + Vec2::X * 6.,
+            CENTER_OF_MASS_COLOR,
+        );
+        gizmos.line_2d(
+            center - Vec2::Y * 6.,
+            center + Vec2::Y * 6.,
+            CENTER_OF_MASS_COLOR,
+        );
+    }
+}
+
+pub fn spawn_inspector(mut commands: Commands) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(8.),
+                    left: Val::Px(8.),
+                    padding: UiRect::all(Val::Px(6.)),
+                    ..default()
+                },
+                background_color: PANEL_COLOR.into(),
+                visibility: Visibility::Hidden,
+                z_index: ZIndex::Global(30),
+                ..default()
+            },
+            InspectorPanel,
+        ))
+        .with_children(|child_builder| {
+            child_builder.spawn((
+                TextBundle::from_section("", text_style(INSPECTOR_FONT_SIZE)),
+                InspectorText,
+            ));
+        });
+}
+
+pub fn update_inspector(
+    gravity: Res<Gravity>,
+    substep_count: Res<SubstepCount>,
+    bottle_query: Query<(&Position, &AngularVelocity), With<Bottle>>,
+    content_query: Query<&Position, With<BottleContent>>,
+    mut text_query: Query<&mut Text, With<InspectorText>>,
+) {
+    let mut inspector = String::new();
+
+    if let Ok((position, angular_velocity)) = bottle_query.get_single() {
+        let _ = writeln!(
+            inspector,
+            "Bottle angular velocity: {:.2} rad/s",
+            angular_velocity.0
+        );
+
+        for (index, content_position) in content_query.iter().enumerate() {
+            let _ = writeln!(
+                inspector,
+                "Content {index}: ({:.1}, {:.1}), {:.1} from bottle",
+                content_position.x,
+                content_position.y,
+                content_position.distance(position.0)
+            );
+        }
+    }
+
+    let _ = writeln!(
+        inspector,
+        "Gravity: ({:.0}, {:.0})",
+        gravity.0.x, gravity.0.y
+    );
+    let _ = write!(inspector, "Substeps: {}", substep_count.0);
+
+    for mut text in &mut text_query {
+        text.sections[0].value.clone_from(&inspector);
+    }
+}
+
+pub fn spawn_tuning_panel(mut commands: Commands, physics_tuning: Res<PhysicsTuning>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(8.),
+                    right: Val::Px(8.),
+                    width: Val::Px(340.),
+                    padding: UiRect::all(Val::Px(6.)),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.),
+                    ..default()
+                },
+                background_color: PANEL_COLOR.into(),
+                visibility: Visibility::Hidden,
+                z_index: ZIndex::Global(30),
+                ..default()
+            },
+            TuningPanel,
+        ))
+        .with_children(|child_builder| {
+            for field in TuningField::ALL {
+                spawn_row(child_builder, |child_builder| {
+                    child_builder.spawn(TextBundle::from_section(
+                        field.name(),
+                        text_style(INSPECTOR_FONT_SIZE),
+                    ));
+                    child_builder.spawn((
+                        TextBundle::from_section("", text_style(INSPECTOR_FONT_SIZE)),
+                        TuningValue(field),
+                    ));
+                    spawn_slider(
+                        child_builder,
+                        field.to_slider(field.get(&physics_tuning)),
+                        TuningSlider(field),
+                    );
+                });
+            }
+
+            // Web builds can't write back to the assets directory.
+            if cfg!(not(target_arch = "wasm32")) {
+                spawn_menu_button(child_builder, "Save".to_string(), true, TuningSaveButton);
+            }
+        });
+}
+
+pub fn toggle_tuning_panel(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut panel_query: Query<&mut Visibility, With<TuningPanel>>,
+) {
+    if !keys.just_pressed(TUNING_TOGGLE_KEY) {
+        return;
+    }
+
+    for mut visibility in &mut panel_query {
+        *visibility = match *visibility {
+            Visibility::Hidden => Visibility::Inherited,
+            _ => Visibility::Hidden,
+        };
+    }
+}
+
+pub fn apply_tuning_sliders(
+    mut physics_tuning: ResMut<PhysicsTuning>,
+    slider_query: Query<(&Slider, &TuningSlider), Changed<Slider>>,
+) {
+    for (slider, TuningSlider(field)) in &slider_query {
+        let value = field.from_slider(slider.value);
+        if field.get(&physics_tuning) != value {
+            field.set(&mut physics_tuning, value);
+        }
+    }
+}
+
+pub fn sync_tuning_panel(
+    physics_tuning: Res<PhysicsTuning>,
+    mut slider_query: Query<(&mut Slider, &TuningSlider)>,
+    mut value_query: Query<(&mut Text, &TuningValue)>,
+) {
+    for (mut slider, TuningSlider(field)) in &mut slider_query {
+        let value = field.to_slider(field.get(&physics_tuning));
+        if (slider.value - value).abs() > 0.001 {
+            slider.value = value;
+        }
+    }
+
+    for (mut text, TuningValue(field)) in &mut value_query {
+        text.sections[0].value = format!("{:.2}", field.get(&physics_tuning));
+    }
+}
+
+pub fn handle_tuning_save_button(
+    physics_tuning: Res<PhysicsTuning>,
+    button_query: Query<(), With<TuningSaveButton>>,
+    mut activated_event_reader: EventReader<ButtonActivated>,
+) {
+    for ButtonActivated(button) in activated_event_reader.read() {
+        if button_query.contains(*button) {
+            save_physics_tuning(&physics_tuning);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+pub mod components;
+pub mod resources;
+#[cfg(not(target_arch = "wasm32"))]
+mod systems;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::demo::resources::{Autopilot, DemoMode, IdleTime};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::demo::systems::{
+    drive_autopilot, exit_demo, plan_demo_throw, player_input, reset_idle_time,
+    restart_demo_after_landing, start_demo_when_idle,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::game::GameState;
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::prelude::*;
+
+// Plays the game by itself after a while idle on the title screen, until any
+// input hands control back. Each throw is solved for first, so like the daily
+// challenge it is native only.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct DemoPlugin;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Plugin for DemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(IdleTime::default());
+        app.insert_resource(Autopilot::default());
+
+        app.add_systems(OnEnter(GameState::Title), reset_idle_time);
+        app.add_systems(
+            Update,
+            (
+                (reset_idle_time.run_if(player_input), start_demo_when_idle)
+                    .chain()
+                    .run_if(in_state(GameState::Title)),
+                (
+                    plan_demo_throw,
+                    drive_autopilot,
+                    restart_demo_after_landing,
+                    exit_demo.run_if(player_input),
+                )
+                    .chain()
+                    .run_if(resource_exists::<DemoMode>.and_then(in_state(GameState::Playing))),
+            ),
+        );
+    }
+}
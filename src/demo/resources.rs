@@ -0,0 +1,78 @@
+use crate::solver::search::Release;
+use bevy::prelude::*;
+use bevy::tasks::Task;
+
+// Seconds of the swing that gives the bottle its release velocity.
+const SWING_DURATION: f32 = 0.15;
+const LIFT_DURATION: f32 = 0.6;
+const HOLD_DURATION: f32 = 0.3;
+
+// Present while the game plays itself.
+#[derive(Resource, Default)]
+pub struct DemoMode {
+    // Throws so far, which picks the level and the solver's seed.
+    pub throws: u64,
+}
+
+// Seconds on the title screen without input.
+#[derive(Resource, Default)]
+pub struct IdleTime(pub f32);
+
+// Pointer positions over time, pressed at the first and released at the last.
+pub struct GrabPath {
+    pub points: Vec<(f32, Vec2)>,
+}
+
+impl GrabPath {
+    // Lifts the bottle from where it was grabbed to a wind-up point, then
+    // swings it in a straight line so it leaves the hand at the release.
+    pub fn from_release(grab_point: Vec2, release: &Release) -> Self {
+        let windup = release.position - release.linear_velocity * SWING_DURATION;
+
+        Self {
+            points: vec![
+                (0., grab_point),
+                (LIFT_DURATION, windup),
+                (LIFT_DURATION + HOLD_DURATION, windup),
+                (
+                    LIFT_DURATION + HOLD_DURATION + SWING_DURATION,
+                    release.position,
+                ),
+            ],
+        }
+    }
+
+    pub fn start(&self) -> Vec2 {
+        self.points.first().map_or(Vec2::ZERO, |(_, point)| *point)
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.points.last().map_or(0., |(time, _)| *time)
+    }
+
+    pub fn position_at(&self, time: f32) -> Vec2 {
+        let next = self
+            .points
+            .iter()
+            .position(|(point_time, _)| *point_time > time)
+            .unwrap_or(self.points.len());
+
+        let previous = next.checked_sub(1).map(|previous| self.points[previous]);
+        match (previous, self.points.get(next).copied()) {
+            (Some((from_time, from)), Some((to_time, to))) => {
+                from.lerp(to, (time - from_time) / (to_time - from_time))
+            }
+            (Some((_, point)), None) | (None, Some((_, point))) => point,
+            (None, None) => Vec2::ZERO,
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct Autopilot {
+    pub plan: Option<Task<Option<Release>>>,
+    pub path: Option<GrabPath>,
+    pub elapsed: f32,
+    // Counts down after a landing before the next throw.
+    pub restart_timer: Option<Timer>,
+}
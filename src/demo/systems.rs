@@ -0,0 +1,241 @@
+use crate::bottle::components::Bottle;
+use crate::bottle::events::{BottleLanded, GrabInput};
+use crate::bottle::resources::ThrowMode;
+use crate::bottle::systems::BOTTLE_BODY_SIZE;
+use crate::breakables::events::BottleShattered;
+use crate::daily::DAILY_PREFIX;
+use crate::demo::components::DemoBanner;
+use crate::demo::resources::{Autopilot, DemoMode, GrabPath, IdleTime};
+use crate::game::GameState;
+use crate::levels::assets::Level;
+use crate::levels::events::StartLevel;
+use crate::levels::resources::Levels;
+use crate::solver::search::{solve, Release, SolverConfig, Target};
+use crate::ui::widgets::{spawn_text, text_style, FONT_SIZE, PANEL_COLOR};
+use avian2d::prelude::*;
+use bevy::input::touch::Touches;
+use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool};
+
+const DEMO_IDLE_DURATION: f32 = 20.;
+const DEMO_RESTART_DELAY: f32 = 2.;
+// Players tend to grab the bottle by the neck.
+const GRAB_OFFSET: Vec2 = Vec2::new(0., BOTTLE_BODY_SIZE.y / 4.);
+// Tossed up with a flip, for when the solver comes up empty.
+const FALLBACK_RELEASE_OFFSET: Vec2 = Vec2::new(40., 140.);
+const FALLBACK_LINEAR_VELOCITY: Vec2 = Vec2::new(250., 1100.);
+const FALLBACK_ANGULAR_VELOCITY: f32 = -12.;
+
+pub fn player_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+) -> bool {
+    keys.get_just_pressed().next().is_some()
+        || mouse_buttons.get_just_pressed().next().is_some()
+        || touches.any_just_pressed()
+        || gamepad_buttons.get_just_pressed().next().is_some()
+}
+
+pub fn reset_idle_time(mut idle_time: ResMut<IdleTime>) {
+    idle_time.0 = 0.;
+}
+
+// Plays the campaign levels that are thrown by grabbing, one throw each.
+fn start_demo_throw(
+    demo_mode: &DemoMode,
+    levels: &Levels,
+    level_assets: &Assets<Level>,
+    autopilot: &mut Autopilot,
+    start_level_event_writer: &mut EventWriter<StartLevel>,
+) {
+    let candidates: Vec<(usize, &Level)> = levels
+        .paths
+        .iter()
+        .enumerate()
+        .filter(|(_, path)| !path.starts_with(DAILY_PREFIX))
+        .filter_map(|(index, _)| Some((index, levels.get(index, level_assets)?)))
+        .filter(|(_, level)| level.throw_mode == ThrowMode::DirectGrab)
+        .collect();
+
+    if candidates.is_empty() {
+        return;
+    }
+
+    let (index, level) = candidates[demo_mode.throws as usize % candidates.len()];
+    let level = level.clone();
+    let config = SolverConfig {
+        seed: demo_mode.throws,
+        ..default()
+    };
+
+    *autopilot = Autopilot {
+        plan: Some(AsyncComputeTaskPool::get().spawn(async move {
+            solve(&level, Target::AnyPlatform, &config)
+                .first()
+                .map(|solution| solution.release)
+        })),
+        ..default()
+    };
+    start_level_event_writer.send(StartLevel(index));
+}
+
+pub fn start_demo_when_idle(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut idle_time: ResMut<IdleTime>,
+    levels: Res<Levels>,
+    level_assets: Res<Assets<Level>>,
+    mut autopilot: ResMut<Autopilot>,
+    mut start_level_event_writer: EventWriter<StartLevel>,
+) {
+    idle_time.0 += time.delta_seconds();
+    if idle_time.0 < DEMO_IDLE_DURATION {
+        return;
+    }
+
+    idle_time.0 = 0.;
+    let demo_mode = DemoMode::default();
+    start_demo_throw(
+        &demo_mode,
+        &levels,
+        &level_assets,
+        &mut autopilot,
+        &mut start_level_event_writer,
+    );
+    commands.insert_resource(demo_mode);
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(24.),
+                    width: Val::Percent(100.),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                z_index: ZIndex::Global(10),
+                ..default()
+            },
+            DemoBanner,
+        ))
+        .with_children(|child_builder| {
+            child_builder
+                .spawn(NodeBundle {
+                    style: Style {
+                        padding: UiRect::axes(Val::Px(12.), Val::Px(6.)),
+                        ..default()
+                    },
+                    background_color: PANEL_COLOR.into(),
+                    ..default()
+                })
+                .with_children(|child_builder| {
+                    spawn_text(child_builder, "demo_banner", text_style(FONT_SIZE));
+                });
+        });
+}
+
+pub fn plan_demo_throw(
+    mut autopilot: ResMut<Autopilot>,
+    bottle_query: Query<&Position, With<Bottle>>,
+) {
+    let Ok(bottle_position) = bottle_query.get_single() else {
+        return;
+    };
+
+    let Some(task) = &mut autopilot.plan else {
+        return;
+    };
+
+    let Some(release) = block_on(future::poll_once(task)) else {
+        return;
+    };
+
+    let release = release.unwrap_or(Release {
+        position: bottle_position.0 + FALLBACK_RELEASE_OFFSET,
+        linear_velocity: FALLBACK_LINEAR_VELOCITY,
+        angular_velocity: FALLBACK_ANGULAR_VELOCITY,
+    });
+
+    autopilot.plan = None;
+    autopilot.elapsed = 0.;
+    autopilot.path = Some(GrabPath::from_release(
+        bottle_position.0 + GRAB_OFFSET,
+        &release,
+    ));
+}
+
+// Moves the grab anchor through the same input events a player would cause,
+// so the physics is just as real.
+pub fn drive_autopilot(
+    time: Res<Time>,
+    mut autopilot: ResMut<Autopilot>,
+    mut grab_input_event_writer: EventWriter<GrabInput>,
+) {
+    let elapsed = autopilot.elapsed;
+    let Some(path) = &autopilot.path else {
+        return;
+    };
+
+    if elapsed == 0. {
+        grab_input_event_writer.send(GrabInput::Press(path.start()));
+    } else if elapsed >= path.duration() {
+        grab_input_event_writer.send(GrabInput::Release(path.position_at(elapsed)));
+        autopilot.path = None;
+        return;
+    } else {
+        grab_input_event_writer.send(GrabInput::Drag(path.position_at(elapsed)));
+    }
+
+    autopilot.elapsed += time.delta_seconds();
+}
+
+pub fn restart_demo_after_landing(
+    time: Res<Time>,
+    mut demo_mode: ResMut<DemoMode>,
+    levels: Res<Levels>,
+    level_assets: Res<Assets<Level>>,
+    mut autopilot: ResMut<Autopilot>,
+    mut landed_event_reader: EventReader<BottleLanded>,
+    mut shattered_event_reader: EventReader<BottleShattered>,
+    mut start_level_event_writer: EventWriter<StartLevel>,
+) {
+    if landed_event_reader.read().count() + shattered_event_reader.read().count() > 0 {
+        autopilot.restart_timer = Some(Timer::from_seconds(DEMO_RESTART_DELAY, TimerMode::Once));
+    }
+
+    let Some(restart_timer) = &mut autopilot.restart_timer else {
+        return;
+    };
+
+    if !restart_timer.tick(time.delta()).finished() {
+        return;
+    }
+
+    demo_mode.throws += 1;
+    start_demo_throw(
+        &demo_mode,
+        &levels,
+        &level_assets,
+        &mut autopilot,
+        &mut start_level_event_writer,
+    );
+}
+
+pub fn exit_demo(
+    mut commands: Commands,
+    mut autopilot: ResMut<Autopilot>,
+    banner_query: Query<Entity, With<DemoBanner>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    commands.remove_resource::<DemoMode>();
+    *autopilot = Autopilot::default();
+
+    for banner in &banner_query {
+        commands.entity(banner).despawn_recursive();
+    }
+
+    next_state.set(GameState::Title);
+}
@@ -0,0 +1,19 @@
+use crate::platforms::components::PlatformKind;
+use bevy::prelude::*;
+
+// The editor's panel, which keeps clicks on it from reaching the level.
+#[derive(Component)]
+pub struct EditorToolbar;
+
+#[derive(Component)]
+pub struct EditorStatusText;
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EditorButton {
+    Place(PlatformKind),
+    ThrowMode,
+    LowerPar,
+    RaisePar,
+    TestPlay,
+    Save,
+}
@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+#[derive(Event)]
+pub struct TestPlayLevel;
+
+#[derive(Event)]
+pub struct SaveEditorLevel;
@@ -0,0 +1,51 @@
+pub mod components;
+pub mod events;
+pub mod resources;
+mod systems;
+
+use crate::demo::resources::DemoMode;
+use crate::editor::events::{SaveEditorLevel, TestPlayLevel};
+use crate::editor::resources::{EditorLevel, EditorSelection};
+use crate::editor::systems::{
+    draw_editor_gizmos, draw_editor_status, edit_with_keys, edit_with_mouse, handle_editor_buttons,
+    respawn_editor_level, save_editor_level, spawn_editor_toolbar, start_editing, test_play_level,
+    toggle_editor,
+};
+use crate::game::GameState;
+use crate::ui::UiInput;
+use bevy::prelude::*;
+
+// Lays out levels in the game itself: F2 while playing opens the level in the
+// editor, and again plays what was built.
+pub struct EditorPlugin;
+
+impl Plugin for EditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(EditorSelection::default());
+        app.add_event::<TestPlayLevel>();
+        app.add_event::<SaveEditorLevel>();
+
+        app.add_systems(
+            OnEnter(GameState::Editor),
+            (start_editing, spawn_editor_toolbar),
+        );
+        app.add_systems(
+            Update,
+            (
+                toggle_editor.run_if(not(resource_exists::<DemoMode>)),
+                (
+                    (
+                        edit_with_mouse,
+                        edit_with_keys,
+                        handle_editor_buttons.after(UiInput),
+                    ),
+                    (test_play_level, save_editor_level),
+                    respawn_editor_level.run_if(resource_changed::<EditorLevel>),
+                    (draw_editor_status, draw_editor_gizmos),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Editor).and_then(resource_exists::<EditorLevel>)),
+            ),
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use crate::levels::assets::Level;
+use bevy::prelude::*;
+
+// The level being edited, kept between test plays.
+#[derive(Resource)]
+pub struct EditorLevel {
+    pub index: usize,
+    pub path: String,
+    pub level: Level,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Selection {
+    Platform(usize),
+    Spawn,
+}
+
+#[derive(Resource, Default)]
+pub struct EditorSelection {
+    pub selected: Option<Selection>,
+    // Offset from the pointer to the dragged item, while dragging.
+    pub drag_offset: Option<Vec2>,
+}
@@ -0,0 +1,544 @@
+use crate::bottle::resources::ThrowMode;
+use crate::bottle::systems::{spawn_bottle, world_from_viewport, BOTTLE_BODY_SIZE};
+use crate::breakables::components::GlassBottle;
+use crate::editor::components::{EditorButton, EditorStatusText, EditorToolbar};
+use crate::editor::events::{SaveEditorLevel, TestPlayLevel};
+use crate::editor::resources::{EditorLevel, EditorSelection, Selection};
+use crate::forces::systems::spawn_force_volume;
+use crate::game::GameState;
+use crate::i18n::translate;
+use crate::levels::assets::{Level, PlatformSpec};
+use crate::levels::events::StartLevel;
+use crate::levels::resources::{CurrentLevel, Levels};
+use crate::levels::systems::LevelEntityFilter;
+use crate::platforms::components::{Ground, PlatformKind};
+use crate::platforms::systems::{spawn_ground_targets, spawn_platform};
+use crate::settings::resources::Settings;
+use crate::tuning::assets::PhysicsTuning;
+use crate::ui::components::MenuButton;
+use crate::ui::events::ButtonActivated;
+use crate::ui::widgets::{spawn_text, text_style, FONT_SIZE, PANEL_COLOR};
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use std::f32::consts::PI;
+
+pub const EDITOR_KEY: KeyCode = KeyCode::F2;
+// Where levels that don't come from a file, like the daily one, are saved.
+const CUSTOM_LEVEL_PATH: &str = "levels/custom.level.ron";
+const LEVEL_EXTENSION: &str = ".level.ron";
+const ROTATION_STEP: f32 = PI / 12.;
+const RESIZE_STEP: f32 = 1.1;
+const SCALE_LIMITS: (f32, f32) = (0.25, 4.);
+const PAR_STEP: u32 = 100;
+const THROW_MODES: [ThrowMode; 3] = [
+    ThrowMode::DirectGrab,
+    ThrowMode::Slingshot,
+    ThrowMode::Flick,
+];
+const PLACE_KEYS: [(KeyCode, PlatformKind); 5] = [
+    (KeyCode::Digit1, PlatformKind::CardboardBox),
+    (KeyCode::Digit2, PlatformKind::TrashCan),
+    (KeyCode::Digit3, PlatformKind::Shelf),
+    (KeyCode::Digit4, PlatformKind::Duck),
+    (KeyCode::Digit5, PlatformKind::Plates),
+];
+const OUTLINE_COLOR: Color = Color::srgba(1., 1., 1., 0.4);
+const SPAWN_COLOR: Color = Color::srgb(0.337, 0.706, 0.914);
+
+fn throw_mode_key(throw_mode: ThrowMode) -> &'static str {
+    match throw_mode {
+        ThrowMode::DirectGrab => "throw_direct_grab",
+        ThrowMode::Slingshot => "throw_slingshot",
+        ThrowMode::Flick => "throw_flick",
+    }
+}
+
+fn contains(platform: &PlatformSpec, point: Vec2) -> bool {
+    let local = Vec2::from_angle(-platform.rotation).rotate(point - platform.position);
+    local.abs().cmple(platform.size() / 2.).all()
+}
+
+fn pick(level: &Level, point: Vec2) -> Option<Selection> {
+    if (point - level.bottle_spawn)
+        .abs()
+        .cmple(BOTTLE_BODY_SIZE / 2.)
+        .all()
+    {
+        return Some(Selection::Spawn);
+    }
+
+    // Later platforms are drawn on top, so they're picked first.
+    level
+        .platforms
+        .iter()
+        .rposition(|platform| contains(platform, point))
+        .map(Selection::Platform)
+}
+
+fn position(level: &Level, selection: Selection) -> Option<Vec2> {
+    match selection {
+        Selection::Platform(index) => level.platforms.get(index).map(|platform| platform.position),
+        Selection::Spawn => Some(level.bottle_spawn),
+    }
+}
+
+fn position_mut(level: &mut Level, selection: Selection) -> Option<&mut Vec2> {
+    match selection {
+        Selection::Platform(index) => level
+            .platforms
+            .get_mut(index)
+            .map(|platform| &mut platform.position),
+        Selection::Spawn => Some(&mut level.bottle_spawn),
+    }
+}
+
+fn cursor_position(
+    camera_query: &Query<(&Camera, &GlobalTransform)>,
+    windows: &Query<&Window>,
+) -> Option<Vec2> {
+    let (Ok((camera, camera_transform)), Ok(window)) =
+        (camera_query.get_single(), windows.get_single())
+    else {
+        return None;
+    };
+
+    world_from_viewport(camera, camera_transform, window.cursor_position())
+}
+
+pub fn toggle_editor(
+    keys: Res<ButtonInput<KeyCode>>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut test_play_event_writer: EventWriter<TestPlayLevel>,
+) {
+    if !keys.just_pressed(EDITOR_KEY) {
+        return;
+    }
+
+    match state.get() {
+        GameState::Playing => next_state.set(GameState::Editor),
+        GameState::Editor => {
+            test_play_event_writer.send(TestPlayLevel);
+        }
+        _ => {}
+    }
+}
+
+pub fn start_editing(
+    mut commands: Commands,
+    current_level: Res<CurrentLevel>,
+    levels: Res<Levels>,
+    level_assets: Res<Assets<Level>>,
+    editor_level: Option<ResMut<EditorLevel>>,
+    mut editor_selection: ResMut<EditorSelection>,
+) {
+    *editor_selection = EditorSelection::default();
+
+    // Edits carry over from test plays of the same level.
+    if let Some(mut editor_level) = editor_level {
+        if editor_level.index == current_level.0 {
+            editor_level.set_changed();
+            return;
+        }
+    }
+
+    let (Some(path), Some(level)) = (
+        levels.paths.get(current_level.0),
+        levels.get(current_level.0, &level_assets),
+    ) else {
+        return;
+    };
+
+    commands.insert_resource(EditorLevel {
+        index: current_level.0,
+        path: path.clone(),
+        level: level.clone(),
+    });
+}
+
+pub fn spawn_editor_toolbar(mut commands: Commands) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(12.),
+                    left: Val::Px(12.),
+                    right: Val::Px(12.),
+                    padding: UiRect::all(Val::Px(8.)),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(6.),
+                    ..default()
+                },
+                background_color: PANEL_COLOR.into(),
+                ..default()
+            },
+            Interaction::default(),
+            EditorToolbar,
+            StateScoped(GameState::Editor),
+        ))
+        .with_children(|child_builder| {
+            child_builder
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_wrap: FlexWrap::Wrap,
+                        column_gap: Val::Px(6.),
+                        row_gap: Val::Px(6.),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|child_builder| {
+                    let buttons = PLACE_KEYS
+                        .map(|(_, kind)| (kind.key(), EditorButton::Place(kind)))
+                        .into_iter()
+                        .chain([
+                            ("throw_mode", EditorButton::ThrowMode),
+                            ("editor_lower_par", EditorButton::LowerPar),
+                            ("editor_raise_par", EditorButton::RaisePar),
+                            ("editor_test", EditorButton::TestPlay),
+                            ("editor_save", EditorButton::Save),
+                        ]);
+
+                    for (label, editor_button) in buttons {
+                        child_builder
+                            .spawn((
+                                ButtonBundle {
+                                    style: Style {
+                                        padding: UiRect::axes(Val::Px(10.), Val::Px(4.)),
+                                        ..default()
+                                    },
+                                    background_color: PANEL_COLOR.into(),
+                                    ..default()
+                                },
+                                Outline::new(Val::Px(2.), Val::ZERO, Color::NONE),
+                                MenuButton,
+                                editor_button,
+                            ))
+                            .with_children(|child_builder| {
+                                spawn_text(child_builder, label, text_style(FONT_SIZE));
+                            });
+                    }
+                });
+
+            child_builder.spawn((
+                TextBundle::from_section("", text_style(FONT_SIZE)),
+                EditorStatusText,
+            ));
+            spawn_text(child_builder, "editor_help", text_style(FONT_SIZE * 0.8));
+        });
+}
+
+// Rebuilds the level from its description after every edit, with the same
+// spawn code the game uses. Physics is paused, so nothing moves meanwhile.
+pub fn respawn_editor_level(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    physics_tuning: Res<PhysicsTuning>,
+    editor_level: Res<EditorLevel>,
+    level_entity_query: Query<Entity, LevelEntityFilter>,
+    ground_query: Query<Entity, With<Ground>>,
+) {
+    for entity in &level_entity_query {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    for platform in &editor_level.level.platforms {
+        spawn_platform(&mut commands, &asset_server, platform);
+    }
+    if let Ok(ground) = ground_query.get_single() {
+        spawn_ground_targets(&mut commands, ground, &editor_level.level.ground_targets);
+    }
+    for (index, volume) in editor_level.level.forces.iter().enumerate() {
+        spawn_force_volume(&mut commands, volume, index as u64);
+    }
+    let bottle = spawn_bottle(
+        &mut commands,
+        &asset_server,
+        &physics_tuning,
+        editor_level.level.bottle_spawn,
+        editor_level.level.loose_cap,
+        editor_level.level.empty_bottle,
+    );
+    if editor_level.level.glass_bottle {
+        commands.entity(bottle).insert(GlassBottle);
+    }
+}
+
+pub fn edit_with_mouse(
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    windows: Query<&Window>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    ui_query: Query<&Interaction, Or<(With<Button>, With<EditorToolbar>)>>,
+    mut editor_level: ResMut<EditorLevel>,
+    mut editor_selection: ResMut<EditorSelection>,
+    mut wheel_event_reader: EventReader<MouseWheel>,
+) {
+    let scroll: f32 = wheel_event_reader
+        .read()
+        .map(|wheel| match wheel.unit {
+            MouseScrollUnit::Line => wheel.y,
+            MouseScrollUnit::Pixel => wheel.y / 100.,
+        })
+        .sum();
+
+    if buttons.just_released(MouseButton::Left) {
+        editor_selection.drag_offset = None;
+    }
+
+    let Some(cursor) = cursor_position(&camera_query, &windows) else {
+        return;
+    };
+
+    if buttons.just_pressed(MouseButton::Left) {
+        if ui_query
+            .iter()
+            .any(|interaction| *interaction != Interaction::None)
+        {
+            return;
+        }
+
+        editor_selection.selected = pick(&editor_level.level, cursor);
+        editor_selection.drag_offset = editor_selection
+            .selected
+            .and_then(|selection| position(&editor_level.level, selection))
+            .map(|position| position - cursor);
+    } else if buttons.pressed(MouseButton::Left) {
+        if let (Some(selection), Some(offset)) =
+            (editor_selection.selected, editor_selection.drag_offset)
+        {
+            // Only moves count as edits, so a held button doesn't respawn the level.
+            let target = cursor + offset;
+            if position(&editor_level.level, selection).is_some_and(|current| current != target) {
+                if let Some(position) = position_mut(&mut editor_level.level, selection) {
+                    *position = target;
+                }
+            }
+        }
+    }
+
+    let Some(Selection::Platform(index)) = editor_selection.selected else {
+        return;
+    };
+
+    if scroll == 0. {
+        return;
+    }
+
+    let factor = RESIZE_STEP.powf(scroll);
+    let axes = if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        Vec2::new(factor, 1.)
+    } else if keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]) {
+        Vec2::new(1., factor)
+    } else {
+        Vec2::splat(factor)
+    };
+
+    if let Some(platform) = editor_level.level.platforms.get_mut(index) {
+        platform.scale =
+            (platform.scale * axes).clamp(Vec2::splat(SCALE_LIMITS.0), Vec2::splat(SCALE_LIMITS.1));
+    }
+}
+
+pub fn edit_with_keys(
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    windows: Query<&Window>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut editor_level: ResMut<EditorLevel>,
+    mut editor_selection: ResMut<EditorSelection>,
+    mut test_play_event_writer: EventWriter<TestPlayLevel>,
+    mut save_event_writer: EventWriter<SaveEditorLevel>,
+) {
+    let control = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    if control {
+        if keys.just_pressed(KeyCode::KeyS) {
+            save_event_writer.send(SaveEditorLevel);
+        }
+        return;
+    }
+
+    if keys.just_pressed(KeyCode::KeyT) {
+        test_play_event_writer.send(TestPlayLevel);
+    }
+
+    if let Some(cursor) = cursor_position(&camera_query, &windows) {
+        for (key, kind) in PLACE_KEYS {
+            if keys.just_pressed(key) {
+                let platforms = &mut editor_level.level.platforms;
+                platforms.push(PlatformSpec::new(kind, cursor));
+                editor_selection.selected = Some(Selection::Platform(platforms.len() - 1));
+            }
+        }
+
+        if keys.just_pressed(KeyCode::KeyS) {
+            editor_level.level.bottle_spawn = cursor;
+        }
+    }
+
+    let Some(Selection::Platform(index)) = editor_selection.selected else {
+        return;
+    };
+
+    if keys.any_just_pressed([KeyCode::Delete, KeyCode::Backspace]) {
+        editor_level.level.remove_platform(index);
+        editor_selection.selected = None;
+        return;
+    }
+
+    let turn = if keys.just_pressed(KeyCode::KeyQ) {
+        ROTATION_STEP
+    } else if keys.just_pressed(KeyCode::KeyE) {
+        -ROTATION_STEP
+    } else {
+        return;
+    };
+
+    if let Some(platform) = editor_level.level.platforms.get_mut(index) {
+        platform.rotation += turn;
+    }
+}
+
+pub fn handle_editor_buttons(
+    button_query: Query<&EditorButton>,
+    mut editor_level: ResMut<EditorLevel>,
+    mut editor_selection: ResMut<EditorSelection>,
+    mut activated_event_reader: EventReader<ButtonActivated>,
+    mut test_play_event_writer: EventWriter<TestPlayLevel>,
+    mut save_event_writer: EventWriter<SaveEditorLevel>,
+) {
+    for ButtonActivated(button) in activated_event_reader.read() {
+        let Ok(editor_button) = button_query.get(*button) else {
+            continue;
+        };
+
+        let level = &mut editor_level.level;
+        match *editor_button {
+            // Placed above the spawn point, to be dragged into place.
+            EditorButton::Place(kind) => {
+                let position = level.bottle_spawn + Vec2::new(200., 100.);
+                level.platforms.push(PlatformSpec::new(kind, position));
+                editor_selection.selected = Some(Selection::Platform(level.platforms.len() - 1));
+            }
+            EditorButton::ThrowMode => {
+                let index = THROW_MODES
+                    .iter()
+                    .position(|throw_mode| *throw_mode == level.throw_mode)
+                    .unwrap_or_default();
+                level.throw_mode = THROW_MODES[(index + 1) % THROW_MODES.len()];
+            }
+            EditorButton::LowerPar => level.par_score = level.par_score.saturating_sub(PAR_STEP),
+            EditorButton::RaisePar => level.par_score += PAR_STEP,
+            EditorButton::TestPlay => {
+                test_play_event_writer.send(TestPlayLevel);
+            }
+            EditorButton::Save => {
+                save_event_writer.send(SaveEditorLevel);
+            }
+        }
+    }
+}
+
+pub fn draw_editor_status(
+    settings: Res<Settings>,
+    editor_level: Res<EditorLevel>,
+    mut text_query: Query<&mut Text, With<EditorStatusText>>,
+) {
+    let language = settings.language;
+    let level = &editor_level.level;
+
+    for mut text in &mut text_query {
+        text.sections[0].value = format!(
+            "{}  |  {}: {}  |  {}: {}",
+            level.name,
+            translate(language, "throw_mode"),
+            translate(language, throw_mode_key(level.throw_mode)),
+            translate(language, "par"),
+            level.par_score,
+        );
+    }
+}
+
+pub fn draw_editor_gizmos(
+    settings: Res<Settings>,
+    editor_level: Res<EditorLevel>,
+    editor_selection: Res<EditorSelection>,
+    mut gizmos: Gizmos,
+) {
+    let accent = settings.palette.accent();
+
+    for (index, platform) in editor_level.level.platforms.iter().enumerate() {
+        let color = if editor_selection.selected == Some(Selection::Platform(index)) {
+            accent
+        } else {
+            OUTLINE_COLOR
+        };
+        gizmos.rect_2d(platform.position, platform.rotation, platform.size(), color);
+    }
+
+    let spawn = editor_level.level.bottle_spawn;
+    let color = if editor_selection.selected == Some(Selection::Spawn) {
+        accent
+    } else {
+        SPAWN_COLOR
+    };
+    gizmos.rect_2d(spawn, 0., BOTTLE_BODY_SIZE, color);
+    gizmos.line_2d(spawn - Vec2::X * 10., spawn + Vec2::X * 10., color);
+    gizmos.line_2d(spawn - Vec2::Y * 10., spawn + Vec2::Y * 10., color);
+}
+
+// Plays the edited level in place of the one it was loaded from, until the
+// game is restarted or the file reloads.
+pub fn test_play_level(
+    editor_level: Res<EditorLevel>,
+    levels: Res<Levels>,
+    mut level_assets: ResMut<Assets<Level>>,
+    mut test_play_event_reader: EventReader<TestPlayLevel>,
+    mut start_level_event_writer: EventWriter<StartLevel>,
+) {
+    if test_play_event_reader.read().count() == 0 {
+        return;
+    }
+
+    let Some(handle) = levels.handles.get(editor_level.index) else {
+        return;
+    };
+
+    level_assets.insert(handle.id(), editor_level.level.clone());
+    start_level_event_writer.send(StartLevel(editor_level.index));
+}
+
+fn save_path(editor_level: &EditorLevel) -> &str {
+    if editor_level.path.ends_with(LEVEL_EXTENSION) {
+        &editor_level.path
+    } else {
+        CUSTOM_LEVEL_PATH
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_level(path: &str, level: &Level) {
+    let path = std::path::Path::new("assets").join(path);
+    let result = ron::ser::to_string_pretty(level, ron::ser::PrettyConfig::default())
+        .map_err(|error| error.to_string())
+        .and_then(|value| std::fs::write(&path, value).map_err(|error| error.to_string()));
+
+    match result {
+        Ok(()) => info!("Saved level to {}", path.display()),
+        Err(error) => warn!("Failed to save level: {error}"),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write_level(_path: &str, _level: &Level) {
+    warn!("Saving levels is not supported in web builds");
+}
+
+pub fn save_editor_level(
+    editor_level: Res<EditorLevel>,
+    mut save_event_reader: EventReader<SaveEditorLevel>,
+) {
+    if save_event_reader.read().count() > 0 {
+        write_level(save_path(&editor_level), &editor_level.level);
+    }
+}
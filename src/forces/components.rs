@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::TAU;
+
+// How quickly a conveyor brings what rests on it up to its speed.
+const CONVEYOR_GRIP: f32 = 8.;
+// A conveyor's belt runs along the bottom of its volume.
+pub const BELT_HEIGHT: f32 = 10.;
+
+// A region of a level that pushes, lifts or carries what's inside it. Written
+// in the level file and spawned with the level.
+#[derive(Component, Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub struct ForceVolume {
+    pub position: Vec2,
+    pub size: Vec2,
+    pub kind: ForceKind,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub enum ForceKind {
+    // A steady push, stronger while a gust blows.
+    Wind {
+        acceleration: Vec2,
+        #[serde(default)]
+        gust: Option<Gust>,
+    },
+    // Blows from the volume's edge behind it, weakening towards the far edge.
+    Fan {
+        acceleration: Vec2,
+    },
+    // Only this fraction of gravity is felt inside.
+    LowGravity {
+        gravity_scale: f32,
+    },
+    // A belt along the volume's bottom, carrying what touches it.
+    Conveyor {
+        speed: f32,
+    },
+}
+
+// Gusts come once a period, adding up to `strength` times the wind.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub struct Gust {
+    pub strength: f32,
+    pub period: f32,
+}
+
+impl Gust {
+    pub fn factor(&self, time: f32) -> f32 {
+        1. + self.strength * (TAU * time / self.period).sin().max(0.).powi(2)
+    }
+}
+
+impl ForceVolume {
+    pub fn contains(&self, point: Vec2) -> bool {
+        (point - self.position).abs().cmple(self.size / 2.).all()
+    }
+
+    // How hard wind and fans push a body at `point`, `time` seconds after the
+    // throw.
+    pub fn acceleration(&self, point: Vec2, time: f32) -> Vec2 {
+        if !self.contains(point) {
+            return Vec2::ZERO;
+        }
+
+        match self.kind {
+            ForceKind::Wind { acceleration, gust } => {
+                acceleration * gust.map_or(1., |gust| gust.factor(time))
+            }
+            ForceKind::Fan { acceleration } => {
+                let direction = acceleration.normalize_or_zero();
+                let depth = (direction.abs() * self.size).element_sum();
+                if depth <= 0. {
+                    return Vec2::ZERO;
+                }
+
+                let distance = (point - self.position).dot(direction) + depth / 2.;
+                acceleration * (1. - distance / depth).clamp(0., 1.)
+            }
+            ForceKind::LowGravity { .. } | ForceKind::Conveyor { .. } => Vec2::ZERO,
+        }
+    }
+
+    // The fraction of gravity felt at `point`.
+    pub fn gravity_scale(&self, point: Vec2) -> f32 {
+        match self.kind {
+            ForceKind::LowGravity { gravity_scale } if self.contains(point) => gravity_scale,
+            _ => 1.,
+        }
+    }
+
+    // Whether a contact at `point` is on the conveyor's belt.
+    pub fn on_belt(&self, point: Vec2) -> bool {
+        let bottom = self.position.y - self.size.y / 2.;
+
+        matches!(self.kind, ForceKind::Conveyor { .. })
+            && (point.x - self.position.x).abs() <= self.size.x / 2.
+            && (point.y - bottom).abs() <= BELT_HEIGHT
+    }
+
+    // Change in velocity over one step for a body the belt carries.
+    pub fn belt_velocity_change(&self, velocity: Vec2, delta_seconds: f32) -> Vec2 {
+        let ForceKind::Conveyor { speed } = self.kind else {
+            return Vec2::ZERO;
+        };
+
+        let grip = 1. - (-CONVEYOR_GRIP * delta_seconds).exp();
+        Vec2::new((speed - velocity.x) * grip, 0.)
+    }
+
+    // How fast the volume's streaks drift, to show which way and how hard it pushes.
+    pub fn streak_velocity(&self, time: f32) -> Vec2 {
+        match self.kind {
+            ForceKind::Wind { acceleration, gust } => {
+                acceleration * 0.25 * gust.map_or(1., |gust| gust.factor(time))
+            }
+            ForceKind::Fan { acceleration } => acceleration * 0.25,
+            ForceKind::LowGravity { .. } => Vec2::Y * 40.,
+            ForceKind::Conveyor { speed } => Vec2::X * speed,
+        }
+    }
+}
+
+// A line or bubble drifting through a force volume, at this spot in the
+// volume before drifting.
+#[derive(Component)]
+pub struct ForceStreak {
+    pub start: Vec2,
+}
+
+// How far a volume's streaks have drifted.
+#[derive(Component, Default)]
+pub struct StreakDrift(pub Vec2);
+
+// A body whose `GravityScale` low gravity volumes set, as opposed to one that
+// came with its own.
+#[derive(Component)]
+pub struct ScaledGravity;
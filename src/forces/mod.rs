@@ -0,0 +1,29 @@
+pub mod components;
+pub mod resources;
+pub mod systems;
+
+use crate::bottle::systems::cap_velocities;
+use crate::forces::resources::ForceClock;
+use crate::forces::systems::{animate_force_streaks, apply_forces, reset_force_clock};
+use crate::game::GameState;
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+// Wind, fans, low gravity and conveyors that levels place in parts of the view.
+pub struct ForcesPlugin;
+
+impl Plugin for ForcesPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ForceClock::default());
+
+        app.add_systems(
+            PhysicsSchedule,
+            (reset_force_clock, apply_forces)
+                .chain()
+                .before(cap_velocities)
+                .before(PhysicsStepSet::First)
+                .run_if(in_state(GameState::Playing)),
+        );
+        app.add_systems(Update, animate_force_streaks);
+    }
+}
@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+// Seconds since the latest throw, or since the level started. Gusts follow it,
+// so a replayed throw meets the same wind.
+#[derive(Resource, Default)]
+pub struct ForceClock(pub f32);
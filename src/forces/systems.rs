@@ -0,0 +1,264 @@
+use crate::bottle::events::BottleThrown;
+use crate::forces::components::{
+    ForceKind, ForceStreak, ForceVolume, ScaledGravity, StreakDrift, BELT_HEIGHT,
+};
+use crate::forces::resources::ForceClock;
+use crate::levels::events::StartLevel;
+use crate::random::Rng;
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+const VOLUME_Z: f32 = -1.;
+const STREAK_Z: f32 = 0.1;
+// Area of a volume per streak drawn in it.
+const STREAK_AREA: f32 = 2500.;
+const MAX_STREAKS: usize = 60;
+const STREAK_SIZE: Vec2 = Vec2::new(28., 2.);
+const BUBBLE_SIZE: f32 = 6.;
+const CONVEYOR_STRIPE_SPACING: f32 = 24.;
+const CONVEYOR_STRIPE_WIDTH: f32 = 6.;
+
+fn volume_color(kind: ForceKind) -> Color {
+    match kind {
+        ForceKind::Wind { .. } => Color::srgba(0.8, 0.9, 1., 0.08),
+        ForceKind::Fan { .. } => Color::srgba(0.6, 0.8, 1., 0.12),
+        ForceKind::LowGravity { .. } => Color::srgba(0.7, 0.5, 1., 0.15),
+        ForceKind::Conveyor { .. } => Color::srgba(0.2, 0.2, 0.25, 0.6),
+    }
+}
+
+fn streak_color(kind: ForceKind) -> Color {
+    match kind {
+        ForceKind::Conveyor { .. } => Color::srgba(0.9, 0.75, 0.2, 0.8),
+        _ => Color::srgba(1., 1., 1., 0.5),
+    }
+}
+
+// Where streaks start, and how each one looks.
+fn streaks(volume: &ForceVolume, seed: u64) -> Vec<(Vec2, Sprite, Quat)> {
+    let color = streak_color(volume.kind);
+
+    if let ForceKind::Conveyor { .. } = volume.kind {
+        let count = (volume.size.x / CONVEYOR_STRIPE_SPACING).ceil() as usize;
+        return (0..count)
+            .map(|index| {
+                let sprite = Sprite {
+                    color,
+                    custom_size: Some(Vec2::new(CONVEYOR_STRIPE_WIDTH, BELT_HEIGHT)),
+                    ..default()
+                };
+                let start = Vec2::new(index as f32 * CONVEYOR_STRIPE_SPACING, BELT_HEIGHT / 2.);
+                (start, sprite, Quat::IDENTITY)
+            })
+            .collect();
+    }
+
+    let (size, rotation) = match volume.kind {
+        ForceKind::LowGravity { .. } => (Vec2::splat(BUBBLE_SIZE), Quat::IDENTITY),
+        _ => {
+            let direction = volume.streak_velocity(0.);
+            (
+                STREAK_SIZE,
+                Quat::from_rotation_z(direction.y.atan2(direction.x)),
+            )
+        }
+    };
+
+    let mut rng = Rng::new(seed);
+    let count = ((volume.size.x * volume.size.y / STREAK_AREA) as usize).clamp(1, MAX_STREAKS);
+    (0..count)
+        .map(|_| {
+            let start = Vec2::new(rng.range(0., volume.size.x), rng.range(0., volume.size.y));
+            let sprite = Sprite {
+                color,
+                custom_size: Some(size),
+                ..default()
+            };
+            (start, sprite, rotation)
+        })
+        .collect()
+}
+
+pub fn spawn_force_volume(commands: &mut Commands, volume: &ForceVolume, seed: u64) -> Entity {
+    // Conveyors only show their belt, along the bottom.
+    let (backdrop_size, backdrop_offset) = match volume.kind {
+        ForceKind::Conveyor { .. } => (
+            Vec2::new(volume.size.x, BELT_HEIGHT),
+            Vec2::new(0., (BELT_HEIGHT - volume.size.y) / 2.),
+        ),
+        _ => (volume.size, Vec2::ZERO),
+    };
+
+    commands
+        .spawn((
+            SpatialBundle::from_transform(Transform::from_translation(
+                volume.position.extend(VOLUME_Z),
+            )),
+            *volume,
+            StreakDrift::default(),
+        ))
+        .with_children(|child_builder| {
+            child_builder.spawn(SpriteBundle {
+                sprite: Sprite {
+                    color: volume_color(volume.kind),
+                    custom_size: Some(backdrop_size),
+                    ..default()
+                },
+                transform: Transform::from_translation(backdrop_offset.extend(0.)),
+                ..default()
+            });
+
+            for (start, sprite, rotation) in streaks(volume, seed) {
+                child_builder.spawn((
+                    SpriteBundle {
+                        sprite,
+                        transform: Transform::from_translation(
+                            (start - volume.size / 2.).extend(STREAK_Z),
+                        )
+                        .with_rotation(rotation),
+                        ..default()
+                    },
+                    ForceStreak { start },
+                ));
+            }
+        })
+        .id()
+}
+
+// Runs before each physics step, so the clock and the pushes are the same for a
+// throw however fast the game renders.
+pub fn reset_force_clock(
+    mut force_clock: ResMut<ForceClock>,
+    mut thrown_event_reader: EventReader<BottleThrown>,
+    mut start_level_event_reader: EventReader<StartLevel>,
+) {
+    if thrown_event_reader.read().count() > 0 || start_level_event_reader.read().count() > 0 {
+        force_clock.0 = 0.;
+    }
+}
+
+// Wind and fans push bodies with a force for the step, low gravity scales
+// their gravity and conveyors carry along what touches their belt.
+pub fn apply_forces(
+    mut commands: Commands,
+    time: Res<Time>,
+    collisions: Res<Collisions>,
+    mut force_clock: ResMut<ForceClock>,
+    volume_query: Query<(Entity, &ForceVolume)>,
+    collider_query: Query<(&ColliderParent, &Position, &Rotation)>,
+    mut body_query: Query<(
+        Entity,
+        &RigidBody,
+        &Position,
+        &Mass,
+        &mut LinearVelocity,
+        Option<&mut ExternalForce>,
+        Option<&mut GravityScale>,
+        Has<ScaledGravity>,
+    )>,
+) {
+    let delta_seconds = time.delta_seconds();
+
+    // Bodies on either side of a contact along a belt, paired with its conveyor.
+    let mut carried = HashSet::new();
+    for contacts in collisions
+        .iter()
+        .filter(|contacts| contacts.during_current_frame)
+    {
+        let Ok([(parent_1, position_1, rotation_1), (parent_2, _, _)]) =
+            collider_query.get_many([contacts.entity1, contacts.entity2])
+        else {
+            continue;
+        };
+
+        for contact in contacts
+            .manifolds
+            .iter()
+            .flat_map(|manifold| &manifold.contacts)
+        {
+            let point = contact.global_point1(position_1, rotation_1);
+            for (conveyor, volume) in &volume_query {
+                if volume.on_belt(point) {
+                    carried.insert((parent_1.get(), conveyor));
+                    carried.insert((parent_2.get(), conveyor));
+                }
+            }
+        }
+    }
+
+    for (
+        body,
+        rigid_body,
+        position,
+        mass,
+        mut linear_velocity,
+        external_force,
+        gravity_scale,
+        scaled_gravity,
+    ) in &mut body_query
+    {
+        if !rigid_body.is_dynamic() {
+            continue;
+        }
+
+        let mut acceleration = Vec2::ZERO;
+        let mut scale = 1.;
+        for (volume_entity, volume) in &volume_query {
+            acceleration += volume.acceleration(position.0, force_clock.0);
+            scale *= volume.gravity_scale(position.0);
+
+            if carried.contains(&(body, volume_entity)) {
+                let change = volume.belt_velocity_change(linear_velocity.0, delta_seconds);
+                linear_velocity.0 += change;
+            }
+        }
+
+        let force = acceleration * mass.0;
+        match external_force {
+            Some(mut external_force) if force != Vec2::ZERO => {
+                external_force.apply_force(force);
+            }
+            None if force != Vec2::ZERO => {
+                commands
+                    .entity(body)
+                    .insert(ExternalForce::new(force).with_persistence(false));
+            }
+            _ => {}
+        }
+
+        match gravity_scale {
+            Some(mut gravity_scale) if scaled_gravity => gravity_scale.0 = scale,
+            None if scale != 1. => {
+                commands
+                    .entity(body)
+                    .insert((GravityScale(scale), ScaledGravity));
+            }
+            _ => {}
+        }
+    }
+
+    force_clock.0 += delta_seconds;
+}
+
+pub fn animate_force_streaks(
+    time: Res<Time>,
+    force_clock: Res<ForceClock>,
+    mut volume_query: Query<(&ForceVolume, &mut StreakDrift, &Children)>,
+    mut streak_query: Query<(&ForceStreak, &mut Transform)>,
+) {
+    for (volume, mut drift, children) in &mut volume_query {
+        drift.0 += volume.streak_velocity(force_clock.0) * time.delta_seconds();
+        drift.0 = drift.0.rem_euclid(volume.size);
+
+        for child in children {
+            let Ok((streak, mut transform)) = streak_query.get_mut(*child) else {
+                continue;
+            };
+
+            let local = (streak.start + drift.0).rem_euclid(volume.size) - volume.size / 2.;
+            transform.translation.x = local.x;
+            transform.translation.y = local.y;
+        }
+    }
+}
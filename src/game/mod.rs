@@ -0,0 +1,68 @@
+pub mod resources;
+mod systems;
+
+use crate::bottle::systems::detect_bottle_landing;
+use crate::demo::resources::DemoMode;
+use crate::game::resources::{Progress, Round};
+use crate::game::systems::{
+    end_shattered_round, invalidate_round, pause_physics, reset_round, restart_level,
+    resume_physics, save_progress, score_landings, toggle_pause, PROGRESS_KEY,
+};
+use crate::storage;
+use bevy::prelude::*;
+
+#[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameState {
+    #[default]
+    Loading,
+    Title,
+    LevelSelect,
+    Playing,
+    Paused,
+    RoundSummary,
+    Settings,
+    Achievements,
+    Editor,
+}
+
+// Systems turning landings into points. Whatever reports landings runs before
+// it, and whatever needs the round's final score after it.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Scoring;
+
+pub struct GamePlugin;
+
+impl Plugin for GamePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<GameState>();
+        app.enable_state_scoped_entities::<GameState>();
+        app.insert_resource(Round::default());
+        app.insert_resource(storage::load_ron::<Progress>(PROGRESS_KEY).unwrap_or_default());
+
+        app.add_systems(OnEnter(GameState::Paused), pause_physics);
+        app.add_systems(OnEnter(GameState::Editor), pause_physics);
+        app.add_systems(OnEnter(GameState::Playing), resume_physics);
+        app.add_systems(
+            Update,
+            (
+                reset_round,
+                (
+                    (invalidate_round, end_shattered_round, score_landings)
+                        .chain()
+                        .in_set(Scoring)
+                        .after(detect_bottle_landing)
+                        .run_if(in_state(GameState::Playing)),
+                    restart_level.run_if(in_state(GameState::Playing)),
+                    toggle_pause,
+                )
+                    // The demo's throws don't count and its input is its own.
+                    .run_if(not(resource_exists::<DemoMode>)),
+            ),
+        );
+        app.add_systems(
+            Last,
+            save_progress
+                .run_if(resource_changed::<Progress>.and_then(not(resource_added::<Progress>))),
+        );
+    }
+}
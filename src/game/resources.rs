@@ -0,0 +1,60 @@
+use crate::platforms::events::TargetHit;
+use crate::tricks::events::TricksLanded;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const LANDING_SCORE: u32 = 100;
+
+#[derive(Resource, Default)]
+pub struct Round {
+    pub score: u32,
+    pub streak: u32,
+    pub landings: u32,
+    pub new_best: bool,
+    // Set by an implausible throw, the round's score then can't become a best.
+    pub invalid: bool,
+    // The glass bottle broke, which ends the round.
+    pub shattered: bool,
+    // The content poured out before the bottle came to rest.
+    pub spilled: bool,
+}
+
+impl Round {
+    // Each landing in a row is worth more than the last.
+    pub fn add_landing(&mut self) {
+        self.landings += 1;
+        self.streak += 1;
+        self.score += LANDING_SCORE * self.streak;
+    }
+
+    pub fn add_tricks(&mut self, tricks: &TricksLanded) {
+        self.score += tricks.score();
+    }
+
+    pub fn add_target_hit(&mut self, target_hit: &TargetHit) {
+        self.score += target_hit.points;
+    }
+}
+
+#[derive(Resource, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct Progress {
+    // Best round score per level, keyed by the level's asset path.
+    pub best_scores: BTreeMap<String, u32>,
+    // Most stars earned per level with objectives.
+    pub stars: BTreeMap<String, u32>,
+}
+
+impl Progress {
+    pub fn best_score(&self, level_path: &str) -> u32 {
+        self.best_scores
+            .get(level_path)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn stars(&self, level_path: &str) -> u32 {
+        self.stars.get(level_path).copied().unwrap_or_default()
+    }
+}
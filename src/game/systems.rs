@@ -0,0 +1,133 @@
+use crate::bottle::events::{BottleLanded, ImplausibleThrow};
+use crate::breakables::events::BottleShattered;
+use crate::game::resources::{Progress, Round};
+use crate::game::GameState;
+use crate::levels::events::StartLevel;
+use crate::levels::resources::{CurrentLevel, Levels};
+use crate::platforms::events::TargetHit;
+use crate::settings::resources::Settings;
+use crate::storage;
+use crate::tricks::events::TricksLanded;
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+pub const PROGRESS_KEY: &str = "progress";
+
+pub fn reset_round(
+    mut round: ResMut<Round>,
+    mut start_level_event_reader: EventReader<StartLevel>,
+) {
+    if start_level_event_reader.read().count() > 0 {
+        *round = Round::default();
+    }
+}
+
+pub fn score_landings(
+    mut round: ResMut<Round>,
+    mut progress: ResMut<Progress>,
+    current_level: Res<CurrentLevel>,
+    levels: Res<Levels>,
+    mut landed_event_reader: EventReader<BottleLanded>,
+    mut tricks_event_reader: EventReader<TricksLanded>,
+    mut target_hit_event_reader: EventReader<TargetHit>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let mut scored = false;
+
+    for landed in landed_event_reader.read() {
+        if !landed.standing() {
+            round.spilled |= landed.spilled;
+            next_state.set(GameState::RoundSummary);
+            continue;
+        }
+
+        round.add_landing();
+        scored = true;
+    }
+
+    for tricks in tricks_event_reader.read() {
+        round.add_tricks(tricks);
+        scored = true;
+    }
+
+    for target_hit in target_hit_event_reader.read() {
+        round.add_target_hit(target_hit);
+        scored = true;
+    }
+
+    let Some(level_path) = levels.paths.get(current_level.0) else {
+        return;
+    };
+
+    if scored && !round.invalid && round.score > progress.best_score(level_path) {
+        round.new_best = true;
+        progress.best_scores.insert(level_path.clone(), round.score);
+    }
+}
+
+pub fn invalidate_round(
+    mut round: ResMut<Round>,
+    mut implausible_event_reader: EventReader<ImplausibleThrow>,
+) {
+    if implausible_event_reader.read().count() > 0 {
+        round.invalid = true;
+    }
+}
+
+pub fn end_shattered_round(
+    mut round: ResMut<Round>,
+    mut shattered_event_reader: EventReader<BottleShattered>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if shattered_event_reader.read().count() > 0 {
+        round.shattered = true;
+        next_state.set(GameState::RoundSummary);
+    }
+}
+
+pub fn save_progress(progress: Res<Progress>) {
+    storage::save_ron(PROGRESS_KEY, progress.as_ref());
+}
+
+pub fn toggle_pause(
+    settings: Res<Settings>,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let pressed = keys.just_pressed(settings.bindings.pause)
+        || gamepads.iter().any(|gamepad| {
+            gamepad_buttons.just_pressed(GamepadButton::new(gamepad, GamepadButtonType::Start))
+        });
+
+    if !pressed {
+        return;
+    }
+
+    match state.get() {
+        GameState::Playing => next_state.set(GameState::Paused),
+        GameState::Paused => next_state.set(GameState::Playing),
+        _ => {}
+    }
+}
+
+pub fn restart_level(
+    settings: Res<Settings>,
+    keys: Res<ButtonInput<KeyCode>>,
+    current_level: Res<CurrentLevel>,
+    mut start_level_event_writer: EventWriter<StartLevel>,
+) {
+    if keys.just_pressed(settings.bindings.restart) {
+        start_level_event_writer.send(StartLevel(current_level.0));
+    }
+}
+
+pub fn pause_physics(mut time: ResMut<Time<Physics>>) {
+    time.pause();
+}
+
+pub fn resume_physics(mut time: ResMut<Time<Physics>>) {
+    time.unpause();
+}
@@ -0,0 +1,10 @@
+use crate::ghost::resources::GhostFrame;
+use bevy::prelude::*;
+
+// Replays a recorded round one physics step at a time. Ghosts have no collider,
+// so they pass through the live bottle and the platforms.
+#[derive(Component)]
+pub struct GhostBottle {
+    pub frames: Vec<GhostFrame>,
+    pub frame: usize,
+}
@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+// Writes the current level's best ghost to a file that can be shared.
+#[derive(Event)]
+pub struct ExportGhost;
@@ -0,0 +1,50 @@
+pub mod components;
+pub mod events;
+pub mod resources;
+pub mod systems;
+
+use crate::bottle::systems::detect_bottle_landing;
+use crate::game::resources::Round;
+use crate::game::GameState;
+use crate::ghost::events::ExportGhost;
+use crate::ghost::resources::GhostRecording;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::ghost::systems::{export_ghost, import_ghosts};
+use crate::ghost::systems::{
+    play_ghosts, record_frames, record_throws, save_best_ghost, start_ghosts,
+};
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+pub struct GhostPlugin;
+
+impl Plugin for GhostPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GhostRecording::default());
+        app.add_event::<ExportGhost>();
+
+        app.add_systems(
+            Update,
+            (
+                start_ghosts,
+                // Before the physics steps again, while the bodies are still
+                // exactly as they were released.
+                record_throws
+                    .after(detect_bottle_landing)
+                    .run_if(in_state(GameState::Playing)),
+                save_best_ghost.run_if(resource_changed::<Round>),
+            ),
+        );
+        // One frame per physics step, taken once the step is done.
+        app.add_systems(
+            PhysicsSchedule,
+            (record_frames, play_ghosts)
+                .after(PhysicsStepSet::Last)
+                .run_if(in_state(GameState::Playing)),
+        );
+
+        // Ghost files are read from and written to disk, which web builds can't do.
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(Update, (export_ghost, import_ghosts));
+    }
+}
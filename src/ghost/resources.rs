@@ -0,0 +1,24 @@
+use crate::simulation::ThrowState;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub struct GhostFrame {
+    pub position: Vec2,
+    pub rotation: f32,
+}
+
+// A round on one level: the bottle's pose after every physics step, and how
+// each throw left the hand.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(default)]
+pub struct Ghost {
+    pub level: String,
+    pub score: u32,
+    pub throws: Vec<ThrowState>,
+    pub frames: Vec<GhostFrame>,
+}
+
+// The round being played, recorded in case it becomes the best one.
+#[derive(Resource, Default)]
+pub struct GhostRecording(pub Ghost);
@@ -0,0 +1,284 @@
+use crate::bottle::components::{Bottle, BottleContent, LooseCap};
+use crate::bottle::events::BottleThrown;
+use crate::game::resources::Round;
+use crate::ghost::components::GhostBottle;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::ghost::events::ExportGhost;
+use crate::ghost::resources::{Ghost, GhostFrame, GhostRecording};
+use crate::levels::events::StartLevel;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::levels::resources::CurrentLevel;
+use crate::levels::resources::Levels;
+use crate::physics::TIMESTEP_HZ;
+use crate::settings::resources::Settings;
+use crate::simulation::{body_state, ThrowState};
+use crate::storage;
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+// Five minutes of play, which keeps ghost files small.
+const MAX_FRAMES: usize = TIMESTEP_HZ as usize * 60 * 5;
+// Comfortably more than a ghost of `MAX_FRAMES` takes.
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_GHOST_FILE_LENGTH: u64 = 8 * 1024 * 1024;
+const GHOST_ALPHA: f32 = 0.35;
+
+// "levels/01_first_flip.level.ron" becomes "01_first_flip".
+fn level_name(level_path: &str) -> &str {
+    let file_name = level_path.rsplit('/').next().unwrap_or(level_path);
+    file_name.split('.').next().unwrap_or(file_name)
+}
+
+pub fn ghost_key(level_path: &str) -> String {
+    format!("ghost_{}", level_name(level_path))
+}
+
+fn imported_ghost_key(level_path: &str) -> String {
+    format!("imported_ghost_{}", level_name(level_path))
+}
+
+fn spawn_ghost(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    ghost: &Ghost,
+    color: Color,
+) {
+    let Some(first) = ghost.frames.first() else {
+        return;
+    };
+
+    commands
+        .spawn((
+            SpatialBundle::from_transform(
+                Transform::from_translation(first.position.extend(-0.5))
+                    .with_rotation(Quat::from_rotation_z(first.rotation)),
+            ),
+            GhostBottle {
+                frames: ghost.frames.clone(),
+                frame: 0,
+            },
+        ))
+        .with_children(|child_builder| {
+            child_builder.spawn(SpriteBundle {
+                texture: asset_server.load("plastic_bottle.png"),
+                sprite: Sprite { color, ..default() },
+                transform: Transform::from_xyz(0., 15., 0.).with_scale(Vec3::ONE * 4.),
+                ..default()
+            });
+        });
+}
+
+pub fn start_ghosts(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    levels: Res<Levels>,
+    mut recording: ResMut<GhostRecording>,
+    mut start_level_event_reader: EventReader<StartLevel>,
+) {
+    let Some(StartLevel(index)) = start_level_event_reader.read().last().copied() else {
+        return;
+    };
+
+    let Some(level_path) = levels.paths.get(index) else {
+        return;
+    };
+
+    recording.0 = Ghost {
+        level: level_path.clone(),
+        ..default()
+    };
+
+    if let Some(ghost) = storage::load_ron::<Ghost>(&ghost_key(level_path)) {
+        spawn_ghost(
+            &mut commands,
+            &asset_server,
+            &ghost,
+            Color::WHITE.with_alpha(GHOST_ALPHA),
+        );
+    }
+
+    if let Some(ghost) = storage::load_ron::<Ghost>(&imported_ghost_key(level_path)) {
+        spawn_ghost(
+            &mut commands,
+            &asset_server,
+            &ghost,
+            settings.palette.accent().with_alpha(GHOST_ALPHA),
+        );
+    }
+}
+
+pub fn record_frames(
+    mut recording: ResMut<GhostRecording>,
+    bottle_query: Query<(&Position, &Rotation), With<Bottle>>,
+) {
+    let Ok((position, rotation)) = bottle_query.get_single() else {
+        return;
+    };
+
+    if recording.0.frames.len() < MAX_FRAMES {
+        recording.0.frames.push(GhostFrame {
+            position: position.0,
+            rotation: rotation.as_radians(),
+        });
+    }
+}
+
+pub fn record_throws(
+    mut recording: ResMut<GhostRecording>,
+    mut thrown_event_reader: EventReader<BottleThrown>,
+    bottle_query: Query<(&Position, &Rotation, &LinearVelocity, &AngularVelocity), With<Bottle>>,
+    content_query: Query<(
+        &BottleContent,
+        &Position,
+        &Rotation,
+        &LinearVelocity,
+        &AngularVelocity,
+    )>,
+    cap_query: Query<(&Position, &Rotation, &LinearVelocity, &AngularVelocity), With<LooseCap>>,
+) {
+    if thrown_event_reader.read().count() == 0 {
+        return;
+    }
+
+    let Ok((position, rotation, linear_velocity, angular_velocity)) = bottle_query.get_single()
+    else {
+        return;
+    };
+
+    let mut throw_state = ThrowState {
+        bottle: body_state(position, rotation, linear_velocity, angular_velocity),
+        ..default()
+    };
+
+    for (content, position, rotation, linear_velocity, angular_velocity) in &content_query {
+        if let Some(content_state) = throw_state.contents.get_mut(content.index) {
+            *content_state = body_state(position, rotation, linear_velocity, angular_velocity);
+        }
+    }
+    throw_state.cap = cap_query.get_single().ok().map(
+        |(position, rotation, linear_velocity, angular_velocity)| {
+            body_state(position, rotation, linear_velocity, angular_velocity)
+        },
+    );
+
+    recording.0.throws.push(throw_state);
+}
+
+pub fn save_best_ghost(round: Res<Round>, mut recording: ResMut<GhostRecording>) {
+    if round.invalid || !round.new_best || recording.0.level.is_empty() {
+        return;
+    }
+
+    recording.0.score = round.score;
+    storage::save_ron(&ghost_key(&recording.0.level), &recording.0);
+}
+
+pub fn play_ghosts(mut ghost_query: Query<(&mut GhostBottle, &mut Transform)>) {
+    for (mut ghost, mut transform) in &mut ghost_query {
+        // Finished ghosts stay where they ended up.
+        let Some(frame) = ghost.frames.get(ghost.frame).copied() else {
+            continue;
+        };
+
+        transform.translation = frame.position.extend(transform.translation.z);
+        transform.rotation = Quat::from_rotation_z(frame.rotation);
+        ghost.frame += 1;
+    }
+}
+
+pub fn write_ghost(ghost: &Ghost) -> Result<String, String> {
+    ron::ser::to_string_pretty(ghost, ron::ser::PrettyConfig::default())
+        .map_err(|error| error.to_string())
+}
+
+// Shared ghost files can come from anyone, so overly long ones are turned down
+// rather than replayed.
+pub fn read_ghost(value: &str) -> Result<Ghost, String> {
+    let ghost = ron::from_str::<Ghost>(value).map_err(|error| error.to_string())?;
+    if ghost.frames.len() > MAX_FRAMES {
+        return Err(format!(
+            "{} frames is more than the {MAX_FRAMES} a ghost can have",
+            ghost.frames.len()
+        ));
+    }
+
+    Ok(ghost)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn export_ghost(
+    current_level: Res<CurrentLevel>,
+    levels: Res<Levels>,
+    mut export_ghost_event_reader: EventReader<ExportGhost>,
+) {
+    if export_ghost_event_reader.read().count() == 0 {
+        return;
+    }
+
+    let Some(level_path) = levels.paths.get(current_level.0) else {
+        return;
+    };
+
+    let Some(ghost) = storage::load_ron::<Ghost>(&ghost_key(level_path)) else {
+        warn!("No ghost recorded for {level_path}");
+        return;
+    };
+
+    let directory = std::path::Path::new("ghosts");
+    let path = directory.join(format!("{}.ghost.ron", level_name(level_path)));
+    let result = write_ghost(&ghost).and_then(|value| {
+        std::fs::create_dir_all(directory)
+            .and_then(|_| std::fs::write(&path, value))
+            .map_err(|error| error.to_string())
+    });
+
+    match result {
+        Ok(()) => info!("Exported ghost to {}", path.display()),
+        Err(error) => warn!("Failed to export ghost: {error}"),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_ghost_file(path: &std::path::Path) -> Result<Ghost, String> {
+    let length = std::fs::metadata(path)
+        .map_err(|error| error.to_string())?
+        .len();
+    if length > MAX_GHOST_FILE_LENGTH {
+        return Err(format!("{length} bytes is too large for a ghost file"));
+    }
+
+    std::fs::read_to_string(path)
+        .map_err(|error| error.to_string())
+        .and_then(|value| read_ghost(&value))
+}
+
+// Ghost files dropped on the window replace the level's imported ghost, which
+// races alongside the player's own from the next start of that level.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn import_ghosts(
+    levels: Res<Levels>,
+    mut drag_and_drop_event_reader: EventReader<FileDragAndDrop>,
+) {
+    for event in drag_and_drop_event_reader.read() {
+        let FileDragAndDrop::DroppedFile { path_buf, .. } = event else {
+            continue;
+        };
+
+        let ghost = match read_ghost_file(path_buf) {
+            Ok(ghost) => ghost,
+            Err(error) => {
+                warn!("Failed to import ghost {}: {error}", path_buf.display());
+                continue;
+            }
+        };
+
+        if !levels.paths.contains(&ghost.level) {
+            warn!("Ignoring ghost for unknown level {}", ghost.level);
+            continue;
+        }
+
+        storage::save_ron(&imported_ghost_key(&ghost.level), &ghost);
+        info!("Imported ghost for {}", ghost.level);
+    }
+}
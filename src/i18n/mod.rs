@@ -0,0 +1,192 @@
+use crate::settings::resources::{Language, Settings};
+use bevy::prelude::*;
+
+// Each entry lists a key followed by its English, Swedish and German text, in
+// the order of `Language::ALL`.
+const TRANSLATIONS: &[(&str, [&str; 3])] = &[
+    ("play", ["Play", "Spela", "Spielen"]),
+    ("quit", ["Quit", "Avsluta", "Beenden"]),
+    ("settings", ["Settings", "Inställningar", "Einstellungen"]),
+    (
+        "select_level",
+        ["Select Level", "Välj bana", "Level wählen"],
+    ),
+    ("back", ["Back", "Tillbaka", "Zurück"]),
+    ("paused", ["Paused", "Pausat", "Pausiert"]),
+    ("resume", ["Resume", "Fortsätt", "Weiter"]),
+    ("restart", ["Restart", "Börja om", "Neustart"]),
+    ("quit_to_menu", ["Quit to Menu", "Till menyn", "Zum Menü"]),
+    ("round_over", ["Round Over", "Rundan slut", "Runde vorbei"]),
+    ("retry", ["Retry", "Försök igen", "Nochmal"]),
+    ("next_level", ["Next Level", "Nästa bana", "Nächstes Level"]),
+    ("new_best", ["New best!", "Nytt rekord!", "Neuer Rekord!"]),
+    ("leaderboard", ["Leaderboard", "Topplista", "Bestenliste"]),
+    ("loading", ["Loading...", "Laddar...", "Lädt..."]),
+    (
+        "leaderboard_unavailable",
+        [
+            "Leaderboard unavailable",
+            "Topplistan är inte tillgänglig",
+            "Bestenliste nicht verfügbar",
+        ],
+    ),
+    (
+        "no_scores",
+        ["No scores yet", "Inga poäng än", "Noch keine Punkte"],
+    ),
+    (
+        "export_ghost",
+        ["Export Ghost", "Exportera spöke", "Geist exportieren"],
+    ),
+    ("achievements", ["Achievements", "Prestationer", "Erfolge"]),
+    (
+        "achievement_unlocked",
+        [
+            "Achievement unlocked",
+            "Prestation upplåst",
+            "Erfolg freigeschaltet",
+        ],
+    ),
+    ("unlocked", ["Unlocked", "Upplåst", "Freigeschaltet"]),
+    ("throw_mode", ["Throw", "Kast", "Wurf"]),
+    (
+        "throw_direct_grab",
+        ["Grab", "Grepp", "Greifen"],
+    ),
+    ("throw_slingshot", ["Slingshot", "Slangbella", "Schleuder"]),
+    ("throw_flick", ["Flick", "Svep", "Wischen"]),
+    ("par", ["Par", "Par", "Par"]),
+    ("platform_box", ["Box", "Låda", "Kiste"]),
+    ("platform_trash_can", ["Trash can", "Soptunna", "Mülleimer"]),
+    ("platform_shelf", ["Shelf", "Hylla", "Regal"]),
+    ("platform_duck", ["Duck", "Anka", "Ente"]),
+    ("platform_plates", ["Plates", "Tallrikar", "Teller"]),
+    ("editor_lower_par", ["Par -", "Par -", "Par -"]),
+    ("editor_raise_par", ["Par +", "Par +", "Par +"]),
+    ("editor_test", ["Test (F2)", "Testa (F2)", "Testen (F2)"]),
+    ("editor_save", ["Save", "Spara", "Speichern"]),
+    (
+        "editor_help",
+        [
+            "Drag to move, Q/E rotate, wheel resizes (Shift width, Alt height), Del deletes, 1-5 place, S sets the spawn, Ctrl+S saves",
+            "Dra för att flytta, Q/E roterar, hjulet ändrar storlek (Shift bredd, Alt höjd), Del tar bort, 1-5 placerar, S sätter start, Ctrl+S sparar",
+            "Ziehen verschiebt, Q/E dreht, Mausrad skaliert (Umschalt Breite, Alt Höhe), Entf löscht, 1-5 platziert, S setzt den Start, Strg+S speichert",
+        ],
+    ),
+    ("level_complete", ["Level complete!", "Banan klar!", "Level geschafft!"]),
+    ("level_failed", ["Objective failed!", "Målet misslyckades!", "Ziel verfehlt!"]),
+    ("bottle_shattered", ["The bottle shattered!", "Flaskan gick sönder!", "Die Flasche ist zerbrochen!"]),
+    ("bottle_spilled", ["The bottle spilled!", "Flaskan spillde ut!", "Die Flasche ist ausgelaufen!"]),
+    ("stars", ["Stars", "Stjärnor", "Sterne"]),
+    ("objective_land_on", ["Land on", "Landa på", "Lande auf"]),
+    ("objective_flips", ["Flips in time", "Volter i tid", "Saltos in der Zeit"]),
+    ("objective_max_throws", ["Land within throws", "Landa inom kast", "Lande in Würfen"]),
+    ("objective_rotations", ["Turns in one throw", "Varv i ett kast", "Drehungen in einem Wurf"]),
+    (
+        "objective_moving_platform",
+        ["Land on a moving box", "Landa på en rörlig låda", "Lande auf einer bewegten Kiste"],
+    ),
+    ("trick_single_flip", ["Flip", "Volt", "Salto"]),
+    (
+        "trick_double_flip",
+        ["Double Flip", "Dubbelvolt", "Doppelsalto"],
+    ),
+    (
+        "trick_triple_flip",
+        ["Triple Flip", "Trippelvolt", "Dreifachsalto"],
+    ),
+    ("trick_reverse_flip", ["Reverse", "Baklänges", "Rückwärts"]),
+    (
+        "trick_cap_landing",
+        ["Cap Landing", "Korklandning", "Deckellandung"],
+    ),
+    ("trick_side_roll", ["Side Roll", "Sidorull", "Seitenrolle"]),
+    ("trick_bounce", ["Bounce", "Studs", "Abpraller"]),
+    ("trick_box_surf", ["Box Surf", "Lådsurf", "Kistensurfen"]),
+    (
+        "demo_banner",
+        [
+            "Demo - press any key to play",
+            "Demo - tryck på valfri knapp för att spela",
+            "Demo - beliebige Taste drücken zum Spielen",
+        ],
+    ),
+    (
+        "invalid_throw",
+        [
+            "Implausible throw, score not recorded",
+            "Orimligt kast, poängen sparas inte",
+            "Unmöglicher Wurf, Punkte nicht gewertet",
+        ],
+    ),
+    ("best", ["Best", "Rekord", "Rekord"]),
+    ("score", ["Score", "Poäng", "Punkte"]),
+    ("landings", ["Landings", "Landningar", "Landungen"]),
+    ("locked", ["Locked", "Låst", "Gesperrt"]),
+    ("master_volume", ["Master", "Total", "Gesamt"]),
+    ("music_volume", ["Music", "Musik", "Musik"]),
+    ("sfx_volume", ["Effects", "Effekter", "Effekte"]),
+    ("mute", ["Mute", "Ljud av", "Stumm"]),
+    ("trajectory_preview", ["Trajectory", "Bana", "Flugbahn"]),
+    (
+        "physics_debug",
+        ["Physics debug", "Fysikfelsök", "Physik-Debug"],
+    ),
+    ("fullscreen", ["Fullscreen", "Helskärm", "Vollbild"]),
+    ("language", ["Language", "Språk", "Sprache"]),
+    ("palette", ["Colors", "Färger", "Farben"]),
+    ("palette_standard", ["Standard", "Standard", "Standard"]),
+    (
+        "palette_deuteranopia",
+        ["Deuteranopia", "Deuteranopi", "Deuteranopie"],
+    ),
+    (
+        "palette_protanopia",
+        ["Protanopia", "Protanopi", "Protanopie"],
+    ),
+    (
+        "palette_tritanopia",
+        ["Tritanopia", "Tritanopi", "Tritanopie"],
+    ),
+    ("binding_pause", ["Pause key", "Pausknapp", "Pausetaste"]),
+    (
+        "binding_restart",
+        ["Restart key", "Omstartsknapp", "Neustarttaste"],
+    ),
+    ("binding_confirm", ["Confirm key", "Bekräfta", "Bestätigen"]),
+    (
+        "press_a_key",
+        ["Press a key", "Tryck en tangent", "Taste drücken"],
+    ),
+];
+
+pub fn translate(language: Language, key: &'static str) -> &'static str {
+    let index = Language::ALL
+        .iter()
+        .position(|candidate| *candidate == language)
+        .unwrap_or_default();
+
+    TRANSLATIONS
+        .iter()
+        .find(|(candidate, _)| *candidate == key)
+        .map_or(key, |(_, texts)| texts[index])
+}
+
+#[derive(Component)]
+pub struct Localized(pub &'static str);
+
+pub fn localize_texts(settings: Res<Settings>, mut text_query: Query<(Ref<Localized>, &mut Text)>) {
+    for (localized, mut text) in &mut text_query {
+        if settings.is_changed() || localized.is_added() {
+            text.sections[0].value = translate(settings.language, localized.0).to_string();
+        }
+    }
+}
+
+pub struct I18nPlugin;
+
+impl Plugin for I18nPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, localize_texts);
+    }
+}
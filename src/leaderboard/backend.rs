@@ -0,0 +1,123 @@
+use crate::ghost::resources::Ghost;
+use crate::levels::assets::Level;
+use crate::simulation::{headless_app, replay_round, ThrowState};
+use crate::storage;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{Mutex, PoisonError};
+use thiserror::Error;
+
+// Scores kept per level.
+pub const MAX_ENTRIES: usize = 10;
+
+#[derive(Debug, Error)]
+pub enum LeaderboardError {
+    #[error("could not reach the leaderboard: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("unexpected leaderboard response: {0}")]
+    Protocol(String),
+    #[error("submission rejected: {0}")]
+    Rejected(String),
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct LeaderboardEntry {
+    pub player: String,
+    pub score: u32,
+}
+
+// A claimed round score along with the throws that made it, so whoever keeps
+// the scores can play the round again and check it.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Submission {
+    pub player: String,
+    pub level: String,
+    pub score: u32,
+    pub throws: Vec<ThrowState>,
+}
+
+impl Submission {
+    pub fn from_ghost(player: &str, ghost: &Ghost) -> Self {
+        Self {
+            player: player.to_string(),
+            level: ghost.level.clone(),
+            score: ghost.score,
+            throws: ghost.throws.clone(),
+        }
+    }
+
+    pub fn verify(&self, level: Level) -> Result<(), LeaderboardError> {
+        match replay_round(&mut headless_app(), level, &self.throws) {
+            Some(score) if score == self.score => Ok(()),
+            Some(score) => Err(LeaderboardError::Rejected(format!(
+                "the throws score {score}, not {}",
+                self.score
+            ))),
+            None => Err(LeaderboardError::Rejected(String::from(
+                "the throws don't replay as a round of plausible landings",
+            ))),
+        }
+    }
+}
+
+// Calls may block, so the game makes them off the main thread.
+pub trait LeaderboardBackend: Send + Sync {
+    fn submit(&self, submission: &Submission) -> Result<(), LeaderboardError>;
+
+    // Best first, at most `MAX_ENTRIES`.
+    fn top_scores(&self, level: &str) -> Result<Vec<LeaderboardEntry>, LeaderboardError>;
+}
+
+// Keeps scores in memory, and in storage when given a key. Used for playing
+// offline and by the leaderboard server to keep its own scores.
+#[derive(Default)]
+pub struct LocalLeaderboard {
+    scores: Mutex<BTreeMap<String, Vec<LeaderboardEntry>>>,
+    storage_key: Option<String>,
+}
+
+impl LocalLeaderboard {
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    pub fn persistent(storage_key: &str) -> Self {
+        Self {
+            scores: Mutex::new(storage::load_ron(storage_key).unwrap_or_default()),
+            storage_key: Some(storage_key.to_string()),
+        }
+    }
+}
+
+impl LeaderboardBackend for LocalLeaderboard {
+    fn submit(&self, submission: &Submission) -> Result<(), LeaderboardError> {
+        let mut scores = self.scores.lock().unwrap_or_else(PoisonError::into_inner);
+        let entries = scores.entry(submission.level.clone()).or_default();
+
+        // Players are listed once, with their best score.
+        match entries
+            .iter_mut()
+            .find(|entry| entry.player == submission.player)
+        {
+            Some(entry) => entry.score = entry.score.max(submission.score),
+            None => entries.push(LeaderboardEntry {
+                player: submission.player.clone(),
+                score: submission.score,
+            }),
+        }
+
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.score));
+        entries.truncate(MAX_ENTRIES);
+
+        if let Some(storage_key) = &self.storage_key {
+            storage::save_ron(storage_key, &*scores);
+        }
+
+        Ok(())
+    }
+
+    fn top_scores(&self, level: &str) -> Result<Vec<LeaderboardEntry>, LeaderboardError> {
+        let scores = self.scores.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(scores.get(level).cloned().unwrap_or_default())
+    }
+}
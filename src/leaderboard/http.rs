@@ -0,0 +1,78 @@
+// Client for the leaderboard server in `src/bin/leaderboard_server.rs`, which
+// speaks plain HTTP/1.1 with RON bodies:
+//
+// - `GET /scores/<level path>` returns the level's top scores.
+// - `POST /scores` takes a `Submission`, replays it and records the score.
+
+use crate::leaderboard::backend::{
+    LeaderboardBackend, LeaderboardEntry, LeaderboardError, Submission,
+};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+// Long enough for the server to replay a round.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct HttpLeaderboard {
+    // Host and port, like "127.0.0.1:7878".
+    address: String,
+}
+
+impl HttpLeaderboard {
+    pub fn new(address: &str) -> Self {
+        Self {
+            address: address.to_string(),
+        }
+    }
+
+    fn request(&self, method: &str, path: &str, body: &str) -> Result<String, LeaderboardError> {
+        let mut stream = TcpStream::connect(&self.address)?;
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        stream.set_write_timeout(Some(TIMEOUT))?;
+
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\n\
+             Host: {}\r\n\
+             Content-Type: text/plain; charset=utf-8\r\n\
+             Content-Length: {}\r\n\
+             Connection: close\r\n\r\n{body}",
+            self.address,
+            body.len()
+        )?;
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+
+        let (head, body) = response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| LeaderboardError::Protocol(String::from("missing headers")))?;
+        let status = head
+            .split_whitespace()
+            .nth(1)
+            .ok_or_else(|| LeaderboardError::Protocol(String::from("missing status")))?;
+
+        match status {
+            "200" => Ok(body.to_string()),
+            "400" | "422" => Err(LeaderboardError::Rejected(body.to_string())),
+            _ => Err(LeaderboardError::Protocol(format!(
+                "status {status}: {body}"
+            ))),
+        }
+    }
+}
+
+impl LeaderboardBackend for HttpLeaderboard {
+    fn submit(&self, submission: &Submission) -> Result<(), LeaderboardError> {
+        let body = ron::to_string(submission)
+            .map_err(|error| LeaderboardError::Protocol(error.to_string()))?;
+        self.request("POST", "/scores", &body)?;
+        Ok(())
+    }
+
+    fn top_scores(&self, level: &str) -> Result<Vec<LeaderboardEntry>, LeaderboardError> {
+        let body = self.request("GET", &format!("/scores/{level}"), "")?;
+        ron::from_str(&body).map_err(|error| LeaderboardError::Protocol(error.to_string()))
+    }
+}
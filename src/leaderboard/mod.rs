@@ -0,0 +1,45 @@
+pub mod backend;
+pub mod components;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod http;
+pub mod resources;
+mod systems;
+
+use crate::game::GameState;
+use crate::leaderboard::backend::{LeaderboardBackend, LocalLeaderboard};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::leaderboard::http::HttpLeaderboard;
+use crate::leaderboard::resources::{Leaderboard, LeaderboardRequest, LeaderboardScores};
+use crate::leaderboard::systems::{
+    draw_leaderboard, poll_leaderboard_request, request_leaderboard, spawn_leaderboard_panel,
+    LEADERBOARD_KEY,
+};
+use bevy::prelude::*;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct LeaderboardPlugin {
+    // Address of a leaderboard server. Scores are kept locally without one,
+    // and always in web builds.
+    pub server: Option<String>,
+}
+
+impl Plugin for LeaderboardPlugin {
+    fn build(&self, app: &mut App) {
+        let backend: Arc<dyn LeaderboardBackend> = match &self.server {
+            #[cfg(not(target_arch = "wasm32"))]
+            Some(address) => Arc::new(HttpLeaderboard::new(address)),
+            _ => Arc::new(LocalLeaderboard::persistent(LEADERBOARD_KEY)),
+        };
+
+        app.insert_resource(Leaderboard(backend));
+        app.insert_resource(LeaderboardRequest::default());
+        app.insert_resource(LeaderboardScores::default());
+
+        app.add_systems(
+            OnEnter(GameState::RoundSummary),
+            (request_leaderboard, spawn_leaderboard_panel),
+        );
+        app.add_systems(Update, (poll_leaderboard_request, draw_leaderboard).chain());
+    }
+}
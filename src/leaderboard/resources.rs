@@ -0,0 +1,22 @@
+use crate::leaderboard::backend::{LeaderboardBackend, LeaderboardEntry, LeaderboardError};
+use bevy::prelude::*;
+use std::sync::{Arc, Mutex};
+
+#[derive(Resource, Clone)]
+pub struct Leaderboard(pub Arc<dyn LeaderboardBackend>);
+
+pub type LeaderboardResult = Result<Vec<LeaderboardEntry>, LeaderboardError>;
+
+// Submitting and fetching scores runs on the IO task pool, which leaves the
+// result in this slot. Web builds get no task handle to poll, so the slot is
+// shared instead.
+#[derive(Resource, Default)]
+pub struct LeaderboardRequest(pub Option<Arc<Mutex<Option<LeaderboardResult>>>>);
+
+#[derive(Resource, Default)]
+pub enum LeaderboardScores {
+    #[default]
+    Loading,
+    Loaded(Vec<LeaderboardEntry>),
+    Unavailable,
+}
@@ -0,0 +1,134 @@
+use crate::game::resources::Round;
+use crate::game::GameState;
+use crate::ghost::resources::Ghost;
+use crate::ghost::systems::ghost_key;
+use crate::i18n::translate;
+use crate::leaderboard::backend::Submission;
+use crate::leaderboard::components::LeaderboardText;
+use crate::leaderboard::resources::{Leaderboard, LeaderboardRequest, LeaderboardScores};
+use crate::levels::resources::{CurrentLevel, Levels};
+use crate::settings::resources::Settings;
+use crate::storage;
+use crate::ui::widgets::{spawn_text, text_style, FONT_SIZE, PANEL_COLOR};
+use bevy::prelude::*;
+use bevy::tasks::IoTaskPool;
+use std::sync::{Arc, Mutex, PoisonError};
+
+pub const LEADERBOARD_KEY: &str = "leaderboard";
+
+// Submits a new best round, which has just been saved as the level's ghost,
+// and fetches the level's top scores.
+pub fn request_leaderboard(
+    leaderboard: Res<Leaderboard>,
+    round: Res<Round>,
+    settings: Res<Settings>,
+    current_level: Res<CurrentLevel>,
+    levels: Res<Levels>,
+    mut leaderboard_request: ResMut<LeaderboardRequest>,
+    mut leaderboard_scores: ResMut<LeaderboardScores>,
+) {
+    let Some(level_path) = levels.paths.get(current_level.0).cloned() else {
+        return;
+    };
+
+    let submission = (round.new_best && !round.invalid)
+        .then(|| storage::load_ron::<Ghost>(&ghost_key(&level_path)))
+        .flatten()
+        .map(|ghost| Submission::from_ghost(&settings.player_name, &ghost));
+
+    let backend = leaderboard.0.clone();
+    let slot = Arc::new(Mutex::new(None));
+    *leaderboard_scores = LeaderboardScores::Loading;
+    leaderboard_request.0 = Some(slot.clone());
+
+    IoTaskPool::get()
+        .spawn(async move {
+            let result = submission
+                .map_or(Ok(()), |submission| backend.submit(&submission))
+                .and_then(|_| backend.top_scores(&level_path));
+            *slot.lock().unwrap_or_else(PoisonError::into_inner) = Some(result);
+        })
+        .detach();
+}
+
+pub fn poll_leaderboard_request(
+    mut leaderboard_request: ResMut<LeaderboardRequest>,
+    mut leaderboard_scores: ResMut<LeaderboardScores>,
+) {
+    let Some(slot) = &leaderboard_request.0 else {
+        return;
+    };
+
+    let Some(result) = slot.lock().unwrap_or_else(PoisonError::into_inner).take() else {
+        return;
+    };
+
+    leaderboard_request.0 = None;
+    *leaderboard_scores = match result {
+        Ok(entries) => LeaderboardScores::Loaded(entries),
+        Err(error) => {
+            warn!("{error}");
+            LeaderboardScores::Unavailable
+        }
+    };
+}
+
+pub fn spawn_leaderboard_panel(mut commands: Commands) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(32.),
+                    top: Val::Px(32.),
+                    min_width: Val::Px(180.),
+                    padding: UiRect::all(Val::Px(12.)),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(6.),
+                    ..default()
+                },
+                background_color: PANEL_COLOR.into(),
+                z_index: ZIndex::Global(11),
+                ..default()
+            },
+            StateScoped(GameState::RoundSummary),
+        ))
+        .with_children(|child_builder| {
+            spawn_text(child_builder, "leaderboard", text_style(FONT_SIZE));
+            child_builder.spawn((
+                TextBundle::from_section("", text_style(FONT_SIZE)),
+                LeaderboardText,
+            ));
+        });
+}
+
+pub fn draw_leaderboard(
+    settings: Res<Settings>,
+    leaderboard_scores: Res<LeaderboardScores>,
+    mut text_query: Query<(Ref<LeaderboardText>, &mut Text)>,
+) {
+    for (leaderboard_text, mut text) in &mut text_query {
+        if !leaderboard_scores.is_changed()
+            && !settings.is_changed()
+            && !leaderboard_text.is_added()
+        {
+            continue;
+        }
+
+        text.sections[0].value = match leaderboard_scores.as_ref() {
+            LeaderboardScores::Loading => translate(settings.language, "loading").to_string(),
+            LeaderboardScores::Unavailable => {
+                translate(settings.language, "leaderboard_unavailable").to_string()
+            }
+            LeaderboardScores::Loaded(entries) if entries.is_empty() => {
+                translate(settings.language, "no_scores").to_string()
+            }
+            LeaderboardScores::Loaded(entries) => entries
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| format!("{}. {}  {}", index + 1, entry.player, entry.score))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        };
+    }
+}
@@ -0,0 +1,121 @@
+use crate::bottle::resources::ThrowMode;
+use crate::forces::components::ForceVolume;
+use crate::levels::generator::platform_size;
+use crate::objectives::resources::Objective;
+use crate::platforms::components::{PlatformKind, TargetZone};
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub const CAMPAIGN_CATALOG: &str = "levels/campaign.catalog.ron";
+
+#[derive(Asset, TypePath, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct Level {
+    pub name: String,
+    pub bottle_spawn: Vec2,
+    // Round score needed on this level to unlock the next one.
+    pub par_score: u32,
+    #[serde(default)]
+    pub throw_mode: ThrowMode,
+    #[serde(default)]
+    pub platforms: Vec<PlatformSpec>,
+    // Completing all of these ends the round. Levels without any are played
+    // until a throw misses.
+    #[serde(default)]
+    pub objectives: Vec<Objective>,
+    #[serde(default)]
+    pub ground_targets: Vec<TargetZone>,
+    #[serde(default)]
+    pub forces: Vec<ForceVolume>,
+    // A glass bottle, which shatters when it lands hard on its cap.
+    #[serde(default)]
+    pub glass_bottle: bool,
+    // The cap on its own body, which hard hits knock off to spill the content.
+    #[serde(default)]
+    pub loose_cap: bool,
+    // A bottle with nothing in it, which is far lighter to flip.
+    #[serde(default)]
+    pub empty_bottle: bool,
+}
+
+impl Level {
+    // Removes a platform along with any objective to land on it, and keeps the
+    // other objectives pointing at the same platforms.
+    pub fn remove_platform(&mut self, index: usize) {
+        if index >= self.platforms.len() {
+            return;
+        }
+
+        self.platforms.remove(index);
+        self.objectives.retain_mut(|objective| match objective {
+            Objective::LandOn(platform) if *platform == index => false,
+            Objective::LandOn(platform) => {
+                if *platform > index {
+                    *platform -= 1;
+                }
+                true
+            }
+            _ => true,
+        });
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct PlatformSpec {
+    pub kind: PlatformKind,
+    pub position: Vec2,
+    #[serde(default)]
+    pub rotation: f32,
+    // Stretches the platform along its own axes.
+    #[serde(default = "unscaled")]
+    pub scale: Vec2,
+    // A bullseye on the platform's top face.
+    #[serde(default)]
+    pub target: Option<TargetZone>,
+}
+
+fn unscaled() -> Vec2 {
+    Vec2::ONE
+}
+
+impl PlatformSpec {
+    pub fn new(kind: PlatformKind, position: Vec2) -> Self {
+        Self {
+            kind,
+            position,
+            rotation: 0.,
+            scale: Vec2::ONE,
+            target: None,
+        }
+    }
+
+    pub fn size(&self) -> Vec2 {
+        platform_size(self.kind) * self.scale
+    }
+}
+
+#[derive(Asset, TypePath, Deserialize)]
+pub struct LevelCatalog {
+    pub levels: Vec<String>,
+}
+
+pub fn read_ron_file<T: DeserializeOwned>(path: &Path) -> Result<T, String> {
+    let value = std::fs::read_to_string(path)
+        .map_err(|error| format!("could not read {}: {error}", path.display()))?;
+    ron::from_str(&value).map_err(|error| format!("could not parse {}: {error}", path.display()))
+}
+
+// Reads the campaign's levels and their paths straight from the files, for
+// tools that run outside the game.
+pub fn load_campaign(assets_directory: &Path) -> Result<Vec<(String, Level)>, String> {
+    let catalog: LevelCatalog = read_ron_file(&assets_directory.join(CAMPAIGN_CATALOG))?;
+    catalog
+        .levels
+        .into_iter()
+        .map(|path| {
+            let level = read_ron_file(&assets_directory.join(&path))?;
+            Ok((path, level))
+        })
+        .collect()
+}
@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+// A platform spawned from a level file, by its index in the file.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LevelPlatform(pub usize);
@@ -0,0 +1,4 @@
+use bevy::prelude::*;
+
+#[derive(Event, Clone, Copy)]
+pub struct StartLevel(pub usize);
@@ -0,0 +1,80 @@
+// Builds levels from a seed: the bottle on the left and a few platforms to the
+// right, within reach of a throw and inside the default view.
+
+use crate::levels::assets::{Level, PlatformSpec};
+use crate::platforms::components::PlatformKind;
+use crate::platforms::systems::{
+    CARDBOARD_BOX_SIZE, DUCK_SIZE, FLOOR_LEVEL, PLATES_SIZE, SHELF_SIZE, TRASH_CAN_SIZE,
+};
+use crate::random::Rng;
+use bevy::prelude::*;
+
+const PLATFORM_KINDS: [PlatformKind; 3] = [
+    PlatformKind::CardboardBox,
+    PlatformKind::TrashCan,
+    PlatformKind::Shelf,
+];
+const MAX_PLATFORMS: u64 = 3;
+const SPAWN_X: (f32, f32) = (-300., -200.);
+const SPAWN_Y: f32 = -80.;
+// Distance from the bottle to the nearest platform's edge.
+const FIRST_PLATFORM_DISTANCE: (f32, f32) = (120., 220.);
+const PLATFORM_GAP: (f32, f32) = (30., 90.);
+// Shelf heights above the floor that a throw can still clear.
+const SHELF_HEIGHT: (f32, f32) = (60., 220.);
+// Right edge of the default view.
+const VIEW_RIGHT: f32 = 370.;
+const PAR_SCORE: u32 = 300;
+
+pub fn platform_size(kind: PlatformKind) -> Vec2 {
+    match kind {
+        PlatformKind::CardboardBox => CARDBOARD_BOX_SIZE,
+        PlatformKind::TrashCan => TRASH_CAN_SIZE,
+        PlatformKind::Shelf => SHELF_SIZE,
+        PlatformKind::Duck => DUCK_SIZE,
+        PlatformKind::Plates => PLATES_SIZE,
+    }
+}
+
+pub fn generate_level(name: String, seed: u64) -> Level {
+    let mut rng = Rng::new(seed);
+
+    let spawn_x = rng.range(SPAWN_X.0, SPAWN_X.1);
+    let mut left = spawn_x + rng.range(FIRST_PLATFORM_DISTANCE.0, FIRST_PLATFORM_DISTANCE.1);
+    let count = 1 + rng.next_u64() % MAX_PLATFORMS;
+
+    let mut platforms = Vec::new();
+    for _ in 0..count {
+        let kind = PLATFORM_KINDS[(rng.next_u64() % PLATFORM_KINDS.len() as u64) as usize];
+        let size = platform_size(kind);
+        if left + size.x > VIEW_RIGHT {
+            break;
+        }
+
+        let y = match kind {
+            PlatformKind::Shelf => FLOOR_LEVEL + rng.range(SHELF_HEIGHT.0, SHELF_HEIGHT.1),
+            PlatformKind::CardboardBox
+            | PlatformKind::TrashCan
+            | PlatformKind::Duck
+            | PlatformKind::Plates => FLOOR_LEVEL + size.y / 2.,
+        };
+
+        platforms.push(PlatformSpec::new(kind, Vec2::new(left + size.x / 2., y)));
+
+        left += size.x + rng.range(PLATFORM_GAP.0, PLATFORM_GAP.1);
+    }
+
+    Level {
+        name,
+        bottle_spawn: Vec2::new(spawn_x, SPAWN_Y),
+        par_score: PAR_SCORE,
+        throw_mode: default(),
+        platforms,
+        objectives: Vec::new(),
+        ground_targets: Vec::new(),
+        forces: Vec::new(),
+        glass_bottle: false,
+        loose_cap: false,
+        empty_bottle: false,
+    }
+}
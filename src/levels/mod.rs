@@ -0,0 +1,39 @@
+pub mod assets;
+pub mod components;
+pub mod events;
+pub mod generator;
+pub mod resources;
+pub mod systems;
+
+use crate::game::GameState;
+use crate::levels::assets::{Level, LevelCatalog};
+use crate::levels::events::StartLevel;
+use crate::levels::resources::{CurrentLevel, Levels};
+use crate::levels::systems::{despawn_level, load_level_catalog, load_levels, start_level};
+use crate::ron_asset::RonAssetPlugin;
+use bevy::prelude::*;
+
+pub struct LevelsPlugin;
+
+impl Plugin for LevelsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            RonAssetPlugin::<LevelCatalog>::new(&["catalog.ron"]),
+            RonAssetPlugin::<Level>::new(&["level.ron"]),
+        ));
+        app.insert_resource(Levels::default());
+        app.insert_resource(CurrentLevel::default());
+        app.add_event::<StartLevel>();
+
+        app.add_systems(Startup, load_level_catalog);
+        app.add_systems(OnEnter(GameState::LevelSelect), despawn_level);
+        app.add_systems(OnEnter(GameState::Title), despawn_level);
+        app.add_systems(
+            Update,
+            (
+                load_levels.run_if(in_state(GameState::Loading)),
+                start_level,
+            ),
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use crate::daily::DAILY_PREFIX;
+use crate::game::resources::Progress;
+use crate::levels::assets::{Level, LevelCatalog};
+use bevy::prelude::*;
+
+#[derive(Resource)]
+pub struct LevelCatalogHandle(pub Handle<LevelCatalog>);
+
+#[derive(Resource, Default)]
+pub struct Levels {
+    pub paths: Vec<String>,
+    pub handles: Vec<Handle<Level>>,
+}
+
+impl Levels {
+    pub fn get<'a>(&self, index: usize, level_assets: &'a Assets<Level>) -> Option<&'a Level> {
+        level_assets.get(self.handles.get(index)?)
+    }
+
+    pub fn is_unlocked(
+        &self,
+        index: usize,
+        level_assets: &Assets<Level>,
+        progress: &Progress,
+    ) -> bool {
+        // Daily levels are open to everyone.
+        if self
+            .paths
+            .get(index)
+            .is_some_and(|path| path.starts_with(DAILY_PREFIX))
+        {
+            return true;
+        }
+
+        let Some(previous) = index.checked_sub(1) else {
+            return true;
+        };
+
+        // Completing a level's objectives opens the next one as well.
+        let previous_path = &self.paths[previous];
+        self.get(previous, level_assets).is_some_and(|level| {
+            progress.best_score(previous_path) >= level.par_score
+                || progress.stars(previous_path) > 0
+        })
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct CurrentLevel(pub usize);
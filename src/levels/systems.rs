@@ -0,0 +1,126 @@
+use crate::bottle::components::{
+    Bottle, BottleContent, BottleContentJoint, CapJoint, GrabAnchor, GrabJoint, LooseCap,
+};
+use crate::bottle::systems::spawn_bottle;
+use crate::breakables::components::{Fragment, GlassBottle};
+use crate::forces::components::ForceVolume;
+use crate::forces::systems::spawn_force_volume;
+use crate::game::GameState;
+use crate::ghost::components::GhostBottle;
+use crate::levels::assets::{Level, LevelCatalog, CAMPAIGN_CATALOG};
+use crate::levels::components::LevelPlatform;
+use crate::levels::events::StartLevel;
+use crate::levels::resources::{CurrentLevel, LevelCatalogHandle, Levels};
+use crate::platforms::components::{DynamicPlatform, Ground, GroundTarget, PlatformKind};
+use crate::platforms::systems::{spawn_ground_targets, spawn_platform};
+use crate::tuning::assets::PhysicsTuning;
+use bevy::prelude::*;
+
+pub type LevelEntityFilter = Or<(
+    With<Bottle>,
+    With<BottleContent>,
+    With<BottleContentJoint>,
+    With<GrabAnchor>,
+    With<GrabJoint>,
+    With<DynamicPlatform>,
+    With<PlatformKind>,
+    With<GhostBottle>,
+    With<GroundTarget>,
+    With<ForceVolume>,
+    With<Fragment>,
+    With<LooseCap>,
+    With<CapJoint>,
+)>;
+
+pub fn load_level_catalog(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(LevelCatalogHandle(asset_server.load(CAMPAIGN_CATALOG)));
+}
+
+pub fn load_levels(
+    asset_server: Res<AssetServer>,
+    level_catalog_handle: Res<LevelCatalogHandle>,
+    level_catalogs: Res<Assets<LevelCatalog>>,
+    mut levels: ResMut<Levels>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if levels.paths.is_empty() {
+        let Some(level_catalog) = level_catalogs.get(&level_catalog_handle.0) else {
+            return;
+        };
+
+        levels.paths = level_catalog.levels.clone();
+        levels.handles = level_catalog
+            .levels
+            .iter()
+            .map(|path| asset_server.load(path.clone()))
+            .collect();
+    }
+
+    if levels
+        .handles
+        .iter()
+        .all(|handle| asset_server.is_loaded_with_dependencies(handle))
+    {
+        next_state.set(GameState::Title);
+    }
+}
+
+pub fn despawn_level(mut commands: Commands, level_entity_query: Query<Entity, LevelEntityFilter>) {
+    for entity in &level_entity_query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn start_level(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    physics_tuning: Res<PhysicsTuning>,
+    levels: Res<Levels>,
+    level_assets: Res<Assets<Level>>,
+    mut current_level: ResMut<CurrentLevel>,
+    level_entity_query: Query<Entity, LevelEntityFilter>,
+    ground_query: Query<Entity, With<Ground>>,
+    mut start_level_event_reader: EventReader<StartLevel>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Some(StartLevel(index)) = start_level_event_reader.read().last().copied() else {
+        return;
+    };
+
+    let Some(level) = levels.get(index, &level_assets) else {
+        warn!("Level {index} is not loaded");
+        return;
+    };
+
+    for entity in &level_entity_query {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    for (index, platform) in level.platforms.iter().enumerate() {
+        // Landing on any plate in a stack counts as landing on the stack.
+        for entity in spawn_platform(&mut commands, &asset_server, platform) {
+            commands.entity(entity).insert(LevelPlatform(index));
+        }
+    }
+    if let Ok(ground) = ground_query.get_single() {
+        spawn_ground_targets(&mut commands, ground, &level.ground_targets);
+    }
+    for (index, volume) in level.forces.iter().enumerate() {
+        spawn_force_volume(&mut commands, volume, index as u64);
+    }
+    let bottle = spawn_bottle(
+        &mut commands,
+        &asset_server,
+        &physics_tuning,
+        level.bottle_spawn,
+        level.loose_cap,
+        level.empty_bottle,
+    );
+    if level.glass_bottle {
+        commands.entity(bottle).insert(GlassBottle);
+    }
+
+    commands.insert_resource(level.throw_mode);
+    current_level.0 = index;
+    next_state.set(GameState::Playing);
+}
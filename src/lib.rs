@@ -0,0 +1,37 @@
+// Bevy code commonly triggers these lints and they may be important signals
+// about code quality. They are sometimes hard to avoid though, and the CI
+// workflow treats them as errors, so this allows them throughout the project.
+// Feel free to delete this line.
+#![allow(clippy::too_many_arguments, clippy::type_complexity)]
+
+pub mod achievements;
+pub mod bottle;
+pub mod breakables;
+pub mod daily;
+#[cfg(feature = "debug")]
+pub mod debug;
+pub mod demo;
+#[cfg(feature = "debug")]
+pub mod editor;
+pub mod forces;
+pub mod game;
+pub mod ghost;
+pub mod i18n;
+pub mod leaderboard;
+pub mod levels;
+pub mod menu;
+pub mod objectives;
+pub mod particles;
+pub mod physics;
+pub mod platforms;
+pub mod random;
+pub mod ron_asset;
+pub mod scene;
+pub mod settings;
+pub mod simulation;
+pub mod solver;
+pub mod sound;
+pub mod storage;
+pub mod tricks;
+pub mod tuning;
+pub mod ui;
@@ -1,50 +1,98 @@
-// Bevy code commonly triggers these lints and they may be important signals
-// about code quality. They are sometimes hard to avoid though, and the CI
-// workflow treats them as errors, so this allows them throughout the project.
-// Feel free to delete this line.
-#![allow(clippy::too_many_arguments, clippy::type_complexity)]
-
-mod bottle;
-mod menu;
-pub mod physics;
-mod platforms;
-mod scene;
-
-use crate::bottle::BottlePlugin;
-use crate::menu::MenuPlugin;
-use crate::platforms::PlatformsPlugin;
-use crate::scene::ScenePlugin;
 use avian2d::prelude::*;
 use bevy::asset::AssetMetaCheck;
 use bevy::prelude::*;
 use bevy::window::WindowResolution;
+use bottle_flip::achievements::AchievementsPlugin;
+use bottle_flip::bottle::BottlePlugin;
+use bottle_flip::breakables::BreakablesPlugin;
+#[cfg(not(target_arch = "wasm32"))]
+use bottle_flip::daily::DailyPlugin;
+#[cfg(feature = "debug")]
+use bottle_flip::debug::DebugPlugin;
+#[cfg(not(target_arch = "wasm32"))]
+use bottle_flip::demo::DemoPlugin;
+#[cfg(feature = "debug")]
+use bottle_flip::editor::EditorPlugin;
+use bottle_flip::forces::ForcesPlugin;
+use bottle_flip::game::GamePlugin;
+use bottle_flip::ghost::GhostPlugin;
+use bottle_flip::i18n::I18nPlugin;
+use bottle_flip::leaderboard::LeaderboardPlugin;
+use bottle_flip::levels::LevelsPlugin;
+use bottle_flip::menu::MenuPlugin;
+use bottle_flip::objectives::ObjectivesPlugin;
+use bottle_flip::particles::ParticlesPlugin;
+use bottle_flip::physics::{ImpactsPlugin, LENGTH_UNIT, TIMESTEP_HZ};
+use bottle_flip::platforms::PlatformsPlugin;
+use bottle_flip::scene::ScenePlugin;
+use bottle_flip::settings::SettingsPlugin;
+#[cfg(not(target_arch = "wasm32"))]
+use bottle_flip::solver::HintsPlugin;
+use bottle_flip::sound::SoundPlugin;
+use bottle_flip::tricks::TricksPlugin;
+use bottle_flip::tuning::TuningPlugin;
+use bottle_flip::ui::UiWidgetsPlugin;
 
 fn main() {
-    App::new()
-        .add_plugins(
-            DefaultPlugins
-                .set(AssetPlugin {
-                    // Wasm builds will check for meta files (that don't exist) if this isn't set.
-                    // This causes errors and even panics in web builds on itch.
-                    // See https://github.com/bevyengine/bevy_github_ci_template/issues/48.
-                    meta_check: AssetMetaCheck::Never,
-                    ..default()
-                })
-                .set(ImagePlugin::default_nearest())
-                .set(WindowPlugin {
-                    primary_window: Some(Window {
-                        title: "Bottle Flip".into(),
-                        resolution: WindowResolution::new(740., 360.),
-                        ..default()
-                    }),
+    let mut app = App::new();
+    app.add_plugins(
+        DefaultPlugins
+            .set(AssetPlugin {
+                // Wasm builds will check for meta files (that don't exist) if this isn't set.
+                // This causes errors and even panics in web builds on itch.
+                // See https://github.com/bevyengine/bevy_github_ci_template/issues/48.
+                meta_check: AssetMetaCheck::Never,
+                ..default()
+            })
+            .set(ImagePlugin::default_nearest())
+            .set(WindowPlugin {
+                primary_window: Some(Window {
+                    title: "Bottle Flip".into(),
+                    resolution: WindowResolution::new(740., 360.),
                     ..default()
                 }),
-        )
-        .insert_resource(ClearColor(Color::srgb(0.671, 0.349, 0.49)))
-        .add_plugins(PhysicsPlugins::default().with_length_unit(100.0))
-        // .add_plugins(PhysicsDebugPlugin::default())
-        .add_plugins((ScenePlugin, BottlePlugin, PlatformsPlugin, MenuPlugin))
-        .insert_resource(Gravity(Vec2::NEG_Y * 2400.0))
-        .insert_resource(SubstepCount(6))
-        .run();
+                ..default()
+            }),
+    )
+    .insert_resource(ClearColor(Color::srgb(0.671, 0.349, 0.49)))
+    .insert_resource(Time::new_with(Physics::fixed_hz(TIMESTEP_HZ)))
+    .add_plugins(PhysicsPlugins::default().with_length_unit(LENGTH_UNIT))
+    .add_plugins((
+        ScenePlugin,
+        BottlePlugin,
+        PlatformsPlugin,
+        MenuPlugin,
+        SoundPlugin,
+        SettingsPlugin,
+        UiWidgetsPlugin,
+        ImpactsPlugin,
+        ParticlesPlugin,
+        GamePlugin,
+        LevelsPlugin,
+        GhostPlugin,
+        I18nPlugin,
+        TuningPlugin::default(),
+    ))
+    // Point the game at a leaderboard server, for example one started with
+    // `cargo run --bin leaderboard_server`.
+    .add_plugins(LeaderboardPlugin {
+        server: std::env::var("BOTTLE_FLIP_LEADERBOARD").ok(),
+    })
+    .add_plugins((
+        TricksPlugin,
+        AchievementsPlugin,
+        ObjectivesPlugin,
+        ForcesPlugin,
+        BreakablesPlugin,
+    ));
+
+    #[cfg(not(target_arch = "wasm32"))]
+    app.add_plugins((DailyPlugin, HintsPlugin, DemoPlugin));
+
+    // Test plays run on the edited level, so they'd count towards the save and
+    // the leaderboard like any other round.
+    #[cfg(feature = "debug")]
+    app.add_plugins((DebugPlugin, EditorPlugin));
+
+    app.run();
 }
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct Hud;
+
+#[derive(Component)]
+pub struct RestartButton;
+
+#[derive(Component)]
+pub struct PauseButton;
+
+#[derive(Component)]
+pub struct HintButton;
+
+#[derive(Component)]
+pub struct ScoreText;
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MenuAction {
+    Play,
+    Quit,
+    SelectLevel(usize),
+    BackToTitle,
+    Resume,
+    Restart,
+    Settings,
+    Achievements,
+    NextLevel,
+    ExportGhost,
+    QuitToMenu,
+}
@@ -1,13 +1,46 @@
+pub mod components;
 mod systems;
 
-use crate::menu::systems::{handle_restart_button, spawn_restart_button};
+use crate::game::resources::Round;
+use crate::game::GameState;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::menu::systems::handle_hint_button;
+use crate::menu::systems::{
+    handle_menu_actions, handle_pause_button, handle_restart_button, show_hud, spawn_hud,
+    spawn_level_select, spawn_pause_overlay, spawn_round_summary, spawn_title_screen,
+    update_score_text,
+};
+use crate::objectives::resources::Objectives;
+use crate::settings::resources::Settings;
 use bevy::prelude::*;
 
 pub struct MenuPlugin;
 
 impl Plugin for MenuPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, spawn_restart_button);
-        app.add_systems(Update, handle_restart_button);
+        app.add_systems(Startup, spawn_hud);
+        app.add_systems(OnEnter(GameState::Title), spawn_title_screen);
+        app.add_systems(OnEnter(GameState::LevelSelect), spawn_level_select);
+        app.add_systems(OnEnter(GameState::Paused), spawn_pause_overlay);
+        app.add_systems(OnEnter(GameState::RoundSummary), spawn_round_summary);
+        app.add_systems(
+            Update,
+            (
+                handle_restart_button,
+                handle_pause_button.run_if(in_state(GameState::Playing)),
+                handle_menu_actions,
+                show_hud.run_if(state_changed::<GameState>),
+                update_score_text.run_if(
+                    resource_changed::<Round>
+                        .or_else(resource_changed::<Objectives>)
+                        .or_else(resource_changed::<Settings>),
+                ),
+            ),
+        );
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(
+            Update,
+            handle_hint_button.run_if(in_state(GameState::Playing)),
+        );
     }
 }
@@ -1,10 +1,31 @@
-use crate::bottle::components::{Bottle, BottleContent, BottleContentJoint, GrabAnchor};
-use crate::bottle::systems::spawn_bottle;
-use crate::platforms::components::DynamicPlatform;
-use crate::platforms::systems::spawn_cardboard_box;
+use crate::game::resources::{Progress, Round};
+use crate::game::GameState;
+use crate::ghost::events::ExportGhost;
+use crate::ghost::systems::ghost_key;
+use crate::i18n::translate;
+use crate::levels::assets::Level;
+use crate::levels::events::StartLevel;
+use crate::levels::resources::{CurrentLevel, Levels};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::menu::components::HintButton;
+use crate::menu::components::{Hud, MenuAction, PauseButton, RestartButton, ScoreText};
+use crate::objectives::resources::{Objectives, MAX_STARS};
+use crate::settings::resources::{Settings, SettingsReturnState};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::solver::events::RequestHint;
+use crate::storage;
+use crate::ui::events::ButtonActivated;
+use crate::ui::widgets::{
+    spawn_menu_button, spawn_screen, spawn_text, text_style, Label, FONT_SIZE, PANEL_COLOR,
+};
 use bevy::prelude::*;
 
-pub fn spawn_restart_button(
+const SCREEN_COLOR: Color = Color::srgba(0.176, 0.114, 0.192, 0.75);
+const TITLE_FONT_SIZE: f32 = 48.;
+const HEADING_FONT_SIZE: f32 = 32.;
+const OBJECTIVE_FONT_SIZE: f32 = 18.;
+
+pub fn spawn_hud(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
@@ -14,32 +35,40 @@ pub fn spawn_restart_button(
     let texture_atlas_handle = texture_atlases.add(texture_atlas);
 
     commands
-        .spawn(NodeBundle {
-            style: Style {
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                position_type: PositionType::Absolute,
-                align_items: AlignItems::Start,
-                justify_content: JustifyContent::Start,
-                left: Val::Px(32.),
-                right: Val::Px(32.),
-                top: Val::Px(32.),
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    position_type: PositionType::Absolute,
+                    align_items: AlignItems::Start,
+                    justify_content: JustifyContent::Start,
+                    column_gap: Val::Px(16.),
+                    left: Val::Px(32.),
+                    right: Val::Px(32.),
+                    top: Val::Px(32.),
+                    ..default()
+                },
+                visibility: Visibility::Hidden,
                 ..default()
             },
-            ..default()
-        })
+            Hud,
+        ))
         .with_children(|child_builder| {
             child_builder
-                .spawn(ButtonBundle {
-                    style: Style {
-                        width: Val::Px(17. * 4.),
-                        height: Val::Px(17. * 4.),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(17. * 4.),
+                            height: Val::Px(17. * 4.),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
                         ..default()
                     },
-                    ..default()
-                })
+                    RestartButton,
+                ))
                 .with_children(|child_builder| {
                     child_builder.spawn((
                         ImageBundle {
@@ -54,27 +83,113 @@ pub fn spawn_restart_button(
                         TextureAtlas::from(texture_atlas_handle),
                     ));
                 });
+
+            child_builder
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(17. * 4.),
+                            height: Val::Px(17. * 4.),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        background_color: PANEL_COLOR.into(),
+                        ..default()
+                    },
+                    PauseButton,
+                ))
+                .with_children(|child_builder| {
+                    child_builder.spawn(TextBundle::from_section(
+                        "II",
+                        text_style(HEADING_FONT_SIZE),
+                    ));
+                });
+
+            // Hints are only solved for in native builds.
+            #[cfg(not(target_arch = "wasm32"))]
+            child_builder
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(17. * 4.),
+                            height: Val::Px(17. * 4.),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        background_color: PANEL_COLOR.into(),
+                        ..default()
+                    },
+                    HintButton,
+                ))
+                .with_children(|child_builder| {
+                    child_builder
+                        .spawn(TextBundle::from_section("?", text_style(HEADING_FONT_SIZE)));
+                });
+
+            // The score, then the level's objectives below it.
+            child_builder.spawn((
+                TextBundle::from_sections([
+                    TextSection::new("", text_style(HEADING_FONT_SIZE)),
+                    TextSection::new("", text_style(OBJECTIVE_FONT_SIZE)),
+                ]),
+                ScoreText,
+            ));
         });
 }
 
+pub fn show_hud(state: Res<State<GameState>>, mut hud_query: Query<&mut Visibility, With<Hud>>) {
+    let visible = matches!(
+        state.get(),
+        GameState::Playing | GameState::Paused | GameState::RoundSummary
+    );
+
+    for mut visibility in &mut hud_query {
+        *visibility = if visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+pub fn update_score_text(
+    round: Res<Round>,
+    objectives: Res<Objectives>,
+    current_level: Res<CurrentLevel>,
+    levels: Res<Levels>,
+    level_assets: Res<Assets<Level>>,
+    settings: Res<Settings>,
+    mut text_query: Query<&mut Text, With<ScoreText>>,
+) {
+    let language = settings.language;
+    let platforms = levels
+        .get(current_level.0, &level_assets)
+        .map_or(&[][..], |level| level.platforms.as_slice());
+    let objective_lines: String = objectives
+        .lines(language, platforms)
+        .iter()
+        .map(|line| format!("\n{line}"))
+        .collect();
+
+    for mut text in &mut text_query {
+        text.sections[0].value = format!("{}: {}", translate(language, "score"), round.score);
+        text.sections[1].value.clone_from(&objective_lines);
+    }
+}
+
 pub fn handle_restart_button(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    bottle_query: Query<Entity, With<Bottle>>,
-    bottle_content_query: Query<Entity, With<BottleContent>>,
-    bottle_content_joint_query: Query<Entity, With<BottleContentJoint>>,
-    grab_anchor_query: Query<Entity, With<GrabAnchor>>,
-    dynamic_platform_query: Query<Entity, With<DynamicPlatform>>,
-    mut interaction_query: Query<&Interaction, (Changed<Interaction>, With<Button>)>,
+    current_level: Res<CurrentLevel>,
+    mut interaction_query: Query<&Interaction, (Changed<Interaction>, With<RestartButton>)>,
     mut atlas_images: Query<&mut TextureAtlas>,
+    mut start_level_event_writer: EventWriter<StartLevel>,
 ) {
-    let mut reset_game = false;
     for interaction in &mut interaction_query {
         for mut atlas_image in &mut atlas_images {
             match *interaction {
                 Interaction::Pressed => {
                     atlas_image.index = 1;
-                    reset_game = true;
                 }
                 Interaction::Hovered => {}
                 Interaction::None => {
@@ -82,31 +197,285 @@ pub fn handle_restart_button(
                 }
             }
         }
-    }
 
-    // Quick and dirty reset solution. Should use game state transitions
-    if reset_game {
-        for bottle in &bottle_query {
-            commands.entity(bottle).despawn_recursive();
+        if *interaction == Interaction::Pressed {
+            start_level_event_writer.send(StartLevel(current_level.0));
         }
+    }
+}
 
-        for grab_anchor in &grab_anchor_query {
-            commands.entity(grab_anchor).despawn();
+pub fn handle_pause_button(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<PauseButton>)>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            next_state.set(GameState::Paused);
         }
+    }
+}
 
-        for bottle_content in &bottle_content_query {
-            commands.entity(bottle_content).despawn();
+#[cfg(not(target_arch = "wasm32"))]
+pub fn handle_hint_button(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<HintButton>)>,
+    mut request_hint_event_writer: EventWriter<RequestHint>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            request_hint_event_writer.send(RequestHint);
         }
+    }
+}
 
-        for bottle_content_joint in &bottle_content_joint_query {
-            commands.entity(bottle_content_joint).despawn();
-        }
+fn spawn_heading(child_builder: &mut ChildBuilder, label: impl Into<Label>, font_size: f32) {
+    spawn_text(child_builder, label, text_style(font_size));
+}
 
-        for dynamic_platform in &dynamic_platform_query {
-            commands.entity(dynamic_platform).despawn_recursive();
-        }
+pub fn spawn_title_screen(mut commands: Commands) {
+    spawn_screen(
+        &mut commands,
+        SCREEN_COLOR,
+        StateScoped(GameState::Title),
+        |child_builder| {
+            spawn_heading(child_builder, "Bottle Flip".to_string(), TITLE_FONT_SIZE);
+            spawn_menu_button(child_builder, "play", true, MenuAction::Play);
+            spawn_menu_button(
+                child_builder,
+                "achievements",
+                true,
+                MenuAction::Achievements,
+            );
+            spawn_menu_button(child_builder, "settings", true, MenuAction::Settings);
+
+            // Browsers don't let pages close themselves.
+            if cfg!(not(target_arch = "wasm32")) {
+                spawn_menu_button(child_builder, "quit", true, MenuAction::Quit);
+            }
+        },
+    );
+}
+
+pub fn spawn_level_select(
+    mut commands: Commands,
+    levels: Res<Levels>,
+    level_assets: Res<Assets<Level>>,
+    progress: Res<Progress>,
+    settings: Res<Settings>,
+) {
+    let language = settings.language;
+
+    spawn_screen(
+        &mut commands,
+        SCREEN_COLOR,
+        StateScoped(GameState::LevelSelect),
+        |child_builder| {
+            spawn_heading(child_builder, "select_level", HEADING_FONT_SIZE);
+
+            child_builder
+                .spawn(NodeBundle {
+                    style: Style {
+                        max_width: Val::Px(660.),
+                        flex_wrap: FlexWrap::Wrap,
+                        justify_content: JustifyContent::Center,
+                        column_gap: Val::Px(12.),
+                        row_gap: Val::Px(12.),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|child_builder| {
+                    for (index, path) in levels.paths.iter().enumerate() {
+                        let Some(level) = levels.get(index, &level_assets) else {
+                            continue;
+                        };
+
+                        let unlocked = levels.is_unlocked(index, &level_assets, &progress);
+                        let label = if unlocked && !level.objectives.is_empty() {
+                            format!(
+                                "{}\n{}: {}\n{}: {}/{MAX_STARS}",
+                                level.name,
+                                translate(language, "best"),
+                                progress.best_score(path),
+                                translate(language, "stars"),
+                                progress.stars(path)
+                            )
+                        } else if unlocked {
+                            format!(
+                                "{}\n{}: {}",
+                                level.name,
+                                translate(language, "best"),
+                                progress.best_score(path)
+                            )
+                        } else {
+                            format!("{}\n{}", level.name, translate(language, "locked"))
+                        };
 
-        spawn_cardboard_box(&mut commands, &asset_server);
-        spawn_bottle(commands, asset_server);
+                        spawn_menu_button(
+                            child_builder,
+                            label,
+                            unlocked,
+                            MenuAction::SelectLevel(index),
+                        );
+                    }
+                });
+
+            spawn_menu_button(child_builder, "back", true, MenuAction::BackToTitle);
+        },
+    );
+}
+
+pub fn spawn_pause_overlay(mut commands: Commands) {
+    spawn_screen(
+        &mut commands,
+        SCREEN_COLOR,
+        StateScoped(GameState::Paused),
+        |child_builder| {
+            spawn_heading(child_builder, "paused", HEADING_FONT_SIZE);
+            spawn_menu_button(child_builder, "resume", true, MenuAction::Resume);
+            spawn_menu_button(child_builder, "restart", true, MenuAction::Restart);
+            spawn_menu_button(child_builder, "settings", true, MenuAction::Settings);
+            spawn_menu_button(child_builder, "quit_to_menu", true, MenuAction::QuitToMenu);
+        },
+    );
+}
+
+pub fn spawn_round_summary(
+    mut commands: Commands,
+    round: Res<Round>,
+    objectives: Res<Objectives>,
+    current_level: Res<CurrentLevel>,
+    levels: Res<Levels>,
+    level_assets: Res<Assets<Level>>,
+    progress: Res<Progress>,
+    settings: Res<Settings>,
+) {
+    let language = settings.language;
+    let next_level = current_level.0 + 1;
+    let next_level_unlocked =
+        next_level < levels.paths.len() && levels.is_unlocked(next_level, &level_assets, &progress);
+    let level_path = levels.paths.get(current_level.0);
+    let best_score = level_path.map_or(0, |path| progress.best_score(path));
+    // Ghost files can only be written in native builds.
+    let ghost_exportable = cfg!(not(target_arch = "wasm32"))
+        && level_path.is_some_and(|path| storage::load(&ghost_key(path)).is_some());
+    let level = levels.get(current_level.0, &level_assets);
+    let stars = level.map_or(0, |level| objectives.stars(round.score, level.par_score));
+    let objective_lines = objectives.lines(
+        language,
+        level.map_or(&[][..], |level| level.platforms.as_slice()),
+    );
+
+    spawn_screen(
+        &mut commands,
+        SCREEN_COLOR,
+        StateScoped(GameState::RoundSummary),
+        |child_builder| {
+            spawn_heading(
+                child_builder,
+                if objectives.is_complete() {
+                    "level_complete"
+                } else if objectives.is_failed() {
+                    "level_failed"
+                } else if round.shattered {
+                    "bottle_shattered"
+                } else if round.spilled {
+                    "bottle_spilled"
+                } else {
+                    "round_over"
+                },
+                HEADING_FONT_SIZE,
+            );
+            if !objective_lines.is_empty() {
+                spawn_heading(
+                    child_builder,
+                    format!("{}: {stars}/{MAX_STARS}", translate(language, "stars")),
+                    HEADING_FONT_SIZE,
+                );
+            }
+            for line in objective_lines {
+                spawn_heading(child_builder, line, OBJECTIVE_FONT_SIZE);
+            }
+            spawn_heading(
+                child_builder,
+                format!(
+                    "{}: {}  {}: {}",
+                    translate(language, "score"),
+                    round.score,
+                    translate(language, "landings"),
+                    round.landings
+                ),
+                FONT_SIZE,
+            );
+            spawn_heading(
+                child_builder,
+                if round.invalid {
+                    translate(language, "invalid_throw").to_string()
+                } else if round.new_best {
+                    translate(language, "new_best").to_string()
+                } else {
+                    format!("{}: {best_score}", translate(language, "best"))
+                },
+                FONT_SIZE,
+            );
+            spawn_menu_button(child_builder, "retry", true, MenuAction::Restart);
+            spawn_menu_button(
+                child_builder,
+                "next_level",
+                next_level_unlocked,
+                MenuAction::NextLevel,
+            );
+            spawn_menu_button(
+                child_builder,
+                "export_ghost",
+                ghost_exportable,
+                MenuAction::ExportGhost,
+            );
+            spawn_menu_button(child_builder, "quit_to_menu", true, MenuAction::QuitToMenu);
+        },
+    );
+}
+
+pub fn handle_menu_actions(
+    state: Res<State<GameState>>,
+    current_level: Res<CurrentLevel>,
+    mut settings_return_state: ResMut<SettingsReturnState>,
+    action_query: Query<&MenuAction>,
+    mut activated_event_reader: EventReader<ButtonActivated>,
+    mut start_level_event_writer: EventWriter<StartLevel>,
+    mut export_ghost_event_writer: EventWriter<ExportGhost>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut app_exit_event_writer: EventWriter<AppExit>,
+) {
+    for ButtonActivated(button) in activated_event_reader.read() {
+        let Ok(action) = action_query.get(*button) else {
+            continue;
+        };
+
+        match *action {
+            MenuAction::Play => next_state.set(GameState::LevelSelect),
+            MenuAction::Quit => {
+                app_exit_event_writer.send(AppExit::Success);
+            }
+            MenuAction::SelectLevel(index) => {
+                start_level_event_writer.send(StartLevel(index));
+            }
+            MenuAction::BackToTitle => next_state.set(GameState::Title),
+            MenuAction::Resume => next_state.set(GameState::Playing),
+            MenuAction::Restart => {
+                start_level_event_writer.send(StartLevel(current_level.0));
+            }
+            MenuAction::Settings => {
+                settings_return_state.0 = *state.get();
+                next_state.set(GameState::Settings);
+            }
+            MenuAction::Achievements => next_state.set(GameState::Achievements),
+            MenuAction::NextLevel => {
+                start_level_event_writer.send(StartLevel(current_level.0 + 1));
+            }
+            MenuAction::ExportGhost => {
+                export_ghost_event_writer.send(ExportGhost);
+            }
+            MenuAction::QuitToMenu => next_state.set(GameState::LevelSelect),
+        }
     }
 }
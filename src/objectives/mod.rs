@@ -0,0 +1,37 @@
+pub mod resources;
+mod systems;
+
+use crate::demo::resources::DemoMode;
+use crate::game::{GameState, Scoring};
+use crate::objectives::resources::Objectives;
+use crate::objectives::systems::{
+    check_objectives, count_throws, record_stars, reset_objectives, tick_objectives,
+};
+use bevy::prelude::*;
+
+// Goals set by a level, like landing on a certain box or within a few throws,
+// which end the round with a star rating once they're all done.
+pub struct ObjectivesPlugin;
+
+impl Plugin for ObjectivesPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Objectives::default());
+
+        app.add_systems(OnEnter(GameState::RoundSummary), record_stars);
+        app.add_systems(
+            Update,
+            (
+                reset_objectives,
+                (tick_objectives, count_throws, check_objectives)
+                    .chain()
+                    // The round's score is final for the stars once a landing
+                    // completes the level.
+                    .after(Scoring)
+                    .run_if(
+                        in_state(GameState::Playing).and_then(not(resource_exists::<DemoMode>)),
+                    ),
+            )
+                .chain(),
+        );
+    }
+}
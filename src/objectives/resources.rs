@@ -0,0 +1,188 @@
+use crate::i18n::translate;
+use crate::levels::assets::PlatformSpec;
+use crate::settings::resources::Language;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub const MAX_STARS: u32 = 3;
+
+// A goal a level sets, written in its level file.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub enum Objective {
+    // Land on one of the level's platforms, by its index in the level file.
+    LandOn(usize),
+    // Land this many flips before the time runs out.
+    FlipsWithin { flips: u32, seconds: f32 },
+    // Land before running out of throws.
+    MaxThrows(u32),
+    // Land after turning at least this many full turns in the air.
+    Rotations(u32),
+    // Land on a box while it's moving.
+    MovingPlatform,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ObjectiveStatus {
+    Pending,
+    Complete,
+    Failed,
+}
+
+// What a standing landing achieved, for checking objectives against.
+#[derive(Default, Debug)]
+pub struct ObjectiveLanding {
+    // Indices of the level's platforms the bottle came to rest on.
+    pub platforms: Vec<usize>,
+    pub rotations: u32,
+    pub flipped: bool,
+    pub moving_platform: bool,
+}
+
+// The current level's objectives and how the round is doing on them.
+#[derive(Resource, Default, Debug)]
+pub struct Objectives {
+    pub objectives: Vec<(Objective, ObjectiveStatus)>,
+    pub throws: u32,
+    pub flips: u32,
+    pub elapsed: f32,
+}
+
+impl Objectives {
+    pub fn new(objectives: &[Objective]) -> Self {
+        Self {
+            objectives: objectives
+                .iter()
+                .map(|objective| (*objective, ObjectiveStatus::Pending))
+                .collect(),
+            ..default()
+        }
+    }
+
+    // Levels without objectives are played for score alone and never complete.
+    pub fn is_complete(&self) -> bool {
+        !self.objectives.is_empty()
+            && self
+                .objectives
+                .iter()
+                .all(|(_, status)| *status == ObjectiveStatus::Complete)
+    }
+
+    // A failed objective can't be completed any more, which ends the round.
+    pub fn is_failed(&self) -> bool {
+        self.objectives
+            .iter()
+            .any(|(_, status)| *status == ObjectiveStatus::Failed)
+    }
+
+    pub fn tick(&mut self, delta: f32) {
+        self.elapsed += delta;
+
+        for (objective, status) in &mut self.objectives {
+            if let Objective::FlipsWithin { seconds, .. } = objective {
+                if *status == ObjectiveStatus::Pending && self.elapsed > *seconds {
+                    *status = ObjectiveStatus::Failed;
+                }
+            }
+        }
+    }
+
+    pub fn add_throw(&mut self) {
+        self.throws += 1;
+
+        for (objective, status) in &mut self.objectives {
+            if let Objective::MaxThrows(throws) = objective {
+                if *status == ObjectiveStatus::Pending && self.throws > *throws {
+                    *status = ObjectiveStatus::Failed;
+                }
+            }
+        }
+    }
+
+    pub fn add_landing(&mut self, landing: &ObjectiveLanding) {
+        if landing.flipped {
+            self.flips += 1;
+        }
+
+        for (objective, status) in &mut self.objectives {
+            if *status != ObjectiveStatus::Pending {
+                continue;
+            }
+
+            let complete = match *objective {
+                Objective::LandOn(index) => landing.platforms.contains(&index),
+                Objective::FlipsWithin { flips, seconds } => {
+                    self.flips >= flips && self.elapsed <= seconds
+                }
+                Objective::MaxThrows(throws) => self.throws <= throws,
+                Objective::Rotations(rotations) => landing.rotations >= rotations,
+                Objective::MovingPlatform => landing.moving_platform,
+            };
+
+            if complete {
+                *status = ObjectiveStatus::Complete;
+            }
+        }
+    }
+
+    // One star for completing the level, and one more each for reaching its
+    // par score and twice that.
+    pub fn stars(&self, score: u32, par_score: u32) -> u32 {
+        if !self.is_complete() {
+            return 0;
+        }
+
+        1 + u32::from(score >= par_score) + u32::from(score >= par_score * 2)
+    }
+
+    // A line for the HUD and the round summary, with the progress so far.
+    pub fn describe(
+        &self,
+        index: usize,
+        language: Language,
+        platforms: &[PlatformSpec],
+    ) -> Option<String> {
+        let (objective, status) = self.objectives.get(index)?;
+
+        let text = match *objective {
+            Objective::LandOn(platform) => format!(
+                "{}: {}",
+                translate(language, "objective_land_on"),
+                platforms
+                    .get(platform)
+                    .map_or("?", |platform| translate(language, platform.kind.key())),
+            ),
+            Objective::FlipsWithin { flips, seconds } => format!(
+                "{}: {}/{flips} ({:.0} s)",
+                translate(language, "objective_flips"),
+                self.flips.min(flips),
+                (seconds - self.elapsed).max(0.).ceil(),
+            ),
+            Objective::MaxThrows(throws) => format!(
+                "{}: {}/{throws}",
+                translate(language, "objective_max_throws"),
+                self.throws.min(throws),
+            ),
+            Objective::Rotations(rotations) => format!(
+                "{}: {rotations}",
+                translate(language, "objective_rotations"),
+            ),
+            Objective::MovingPlatform => {
+                translate(language, "objective_moving_platform").to_string()
+            }
+        };
+
+        let marker = match status {
+            ObjectiveStatus::Pending => "[ ]",
+            ObjectiveStatus::Complete => "[x]",
+            ObjectiveStatus::Failed => "[-]",
+        };
+
+        Some(format!("{marker} {text}"))
+    }
+
+    pub fn lines(&self, language: Language, platforms: &[PlatformSpec]) -> Vec<String> {
+        (0..self.objectives.len())
+            .filter_map(|index| self.describe(index, language, platforms))
+            .collect()
+    }
+}
@@ -0,0 +1,114 @@
+use crate::bottle::events::{BottleLanded, BottleThrown};
+use crate::game::resources::{Progress, Round};
+use crate::game::GameState;
+use crate::levels::assets::Level;
+use crate::levels::components::LevelPlatform;
+use crate::levels::events::StartLevel;
+use crate::levels::resources::{CurrentLevel, Levels};
+use crate::objectives::resources::{ObjectiveLanding, Objectives};
+use crate::tricks::resources::{Trick, TrickTracker};
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+pub fn reset_objectives(
+    mut objectives: ResMut<Objectives>,
+    levels: Res<Levels>,
+    level_assets: Res<Assets<Level>>,
+    mut start_level_event_reader: EventReader<StartLevel>,
+) {
+    let Some(StartLevel(index)) = start_level_event_reader.read().last().copied() else {
+        return;
+    };
+
+    *objectives = levels
+        .get(index, &level_assets)
+        .map_or_else(Objectives::default, |level| {
+            Objectives::new(&level.objectives)
+        });
+}
+
+pub fn tick_objectives(
+    time: Res<Time>,
+    mut objectives: ResMut<Objectives>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    // Leaves levels without objectives unchanged, so the HUD isn't redrawn.
+    if objectives.objectives.is_empty() {
+        return;
+    }
+
+    objectives.tick(time.delta_seconds());
+    if objectives.is_failed() {
+        next_state.set(GameState::RoundSummary);
+    }
+}
+
+pub fn count_throws(
+    mut objectives: ResMut<Objectives>,
+    mut thrown_event_reader: EventReader<BottleThrown>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for _ in thrown_event_reader.read() {
+        objectives.add_throw();
+    }
+
+    if objectives.is_failed() {
+        next_state.set(GameState::RoundSummary);
+    }
+}
+
+pub fn check_objectives(
+    mut objectives: ResMut<Objectives>,
+    trick_tracker: Res<TrickTracker>,
+    platform_query: Query<&LevelPlatform>,
+    mut landed_event_reader: EventReader<BottleLanded>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for landed in landed_event_reader.read() {
+        if !landed.standing() || objectives.objectives.is_empty() {
+            continue;
+        }
+
+        let tricks = trick_tracker.tricks(landed.upright, landed.upside_down);
+        objectives.add_landing(&ObjectiveLanding {
+            platforms: trick_tracker
+                .resting_on
+                .iter()
+                .filter_map(|body| platform_query.get(*body).ok())
+                .map(|platform| platform.0)
+                .collect(),
+            rotations: (trick_tracker.rotation.abs() / TAU) as u32,
+            flipped: tricks.iter().any(|trick| trick.is_flip()),
+            moving_platform: tricks.contains(&Trick::BoxSurf),
+        });
+
+        if objectives.is_complete() || objectives.is_failed() {
+            next_state.set(GameState::RoundSummary);
+        }
+    }
+}
+
+pub fn record_stars(
+    objectives: Res<Objectives>,
+    round: Res<Round>,
+    current_level: Res<CurrentLevel>,
+    levels: Res<Levels>,
+    level_assets: Res<Assets<Level>>,
+    mut progress: ResMut<Progress>,
+) {
+    if round.invalid {
+        return;
+    }
+
+    let (Some(level_path), Some(level)) = (
+        levels.paths.get(current_level.0),
+        levels.get(current_level.0, &level_assets),
+    ) else {
+        return;
+    };
+
+    let stars = objectives.stars(round.score, level.par_score);
+    if stars > progress.stars(level_path) {
+        progress.stars.insert(level_path.clone(), stars);
+    }
+}
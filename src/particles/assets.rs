@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+
+#[derive(Asset, TypePath, Deserialize)]
+pub struct ParticleEffects {
+    pub dust: EmitterConfig,
+    pub cardboard_debris: EmitterConfig,
+    pub water_droplets: EmitterConfig,
+    pub shards: EmitterConfig,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct EmitterConfig {
+    pub count: u32,
+    pub lifetime: (f32, f32),
+    pub speed: (f32, f32),
+    // Direction and spread of the emission cone, in degrees.
+    pub angle: f32,
+    pub spread: f32,
+    pub gravity: f32,
+    pub drag: f32,
+    pub size: (f32, f32),
+    pub end_size_scale: f32,
+    pub colors: Vec<[f32; 4]>,
+    pub end_alpha: f32,
+    pub spin: f32,
+}
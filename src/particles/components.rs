@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct Particle {
+    pub velocity: Vec2,
+    pub age: f32,
+    pub lifetime: f32,
+    pub gravity: f32,
+    pub drag: f32,
+    pub size: f32,
+    pub end_size: f32,
+    pub alpha: f32,
+    pub end_alpha: f32,
+    pub spin: f32,
+}
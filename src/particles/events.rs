@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParticleEffect {
+    Dust,
+    CardboardDebris,
+    WaterDroplets,
+    Shards,
+}
+
+#[derive(Event, Clone, Copy)]
+pub struct EmitParticles {
+    pub effect: ParticleEffect,
+    pub position: Vec2,
+    // Scales the particle count, from 0 to 1.
+    pub intensity: f32,
+}
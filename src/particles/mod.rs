@@ -0,0 +1,36 @@
+pub mod assets;
+mod components;
+pub mod events;
+mod resources;
+mod systems;
+
+use crate::particles::assets::ParticleEffects;
+use crate::particles::events::EmitParticles;
+use crate::particles::resources::ParticleRng;
+use crate::particles::systems::{
+    emit_break_particles, emit_impact_particles, emit_spill_particles, load_particle_effects,
+    spawn_particles, update_particles,
+};
+use crate::ron_asset::RonAssetPlugin;
+use bevy::prelude::*;
+
+pub struct ParticlesPlugin;
+
+impl Plugin for ParticlesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RonAssetPlugin::<ParticleEffects>::new(&["particles.ron"]));
+        app.insert_resource(ParticleRng::default());
+        app.add_event::<EmitParticles>();
+
+        app.add_systems(Startup, load_particle_effects);
+        app.add_systems(
+            Update,
+            (
+                emit_impact_particles,
+                emit_break_particles,
+                emit_spill_particles,
+                (spawn_particles, update_particles).chain(),
+            ),
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use crate::particles::assets::ParticleEffects;
+use crate::random::Rng;
+use bevy::prelude::*;
+
+#[derive(Resource)]
+pub struct ParticleEffectsHandle(pub Handle<ParticleEffects>);
+
+#[derive(Resource)]
+pub struct ParticleRng(pub Rng);
+
+impl Default for ParticleRng {
+    fn default() -> Self {
+        Self(Rng::new(0x5eed))
+    }
+}
@@ -0,0 +1,202 @@
+use crate::bottle::components::Bottle;
+use crate::bottle::events::ContentSpilled;
+use crate::breakables::components::BreakMaterial;
+use crate::breakables::events::PropDamaged;
+use crate::particles::assets::ParticleEffects;
+use crate::particles::components::Particle;
+use crate::particles::events::{EmitParticles, ParticleEffect};
+use crate::particles::resources::{ParticleEffectsHandle, ParticleRng};
+use crate::physics::events::Impact;
+use crate::platforms::components::{DynamicPlatform, Ground, Surface};
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+const PARTICLE_Z: f32 = 5.;
+const DUST_MIN_SPEED: f32 = 200.;
+const DUST_MAX_SPEED: f32 = 1600.;
+const DEBRIS_MIN_SPEED: f32 = 900.;
+const DEBRIS_MAX_SPEED: f32 = 2400.;
+const SPLASH_MIN_SPEED: f32 = 1400.;
+const SPLASH_MAX_SPEED: f32 = 2800.;
+
+fn normalized(value: f32, min: f32, max: f32) -> f32 {
+    ((value - min) / (max - min)).clamp(0., 1.)
+}
+
+fn lerp(from: f32, to: f32, t: f32) -> f32 {
+    from + (to - from) * t
+}
+
+pub fn load_particle_effects(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(ParticleEffectsHandle(
+        asset_server.load("particles/effects.particles.ron"),
+    ));
+}
+
+pub fn emit_impact_particles(
+    mut impact_event_reader: EventReader<Impact>,
+    ground_query: Query<(), With<Ground>>,
+    falling_query: Query<(), Or<(With<Bottle>, With<DynamicPlatform>)>>,
+    surface_query: Query<&Surface, With<DynamicPlatform>>,
+    bottle_query: Query<(), With<Bottle>>,
+    mut emit_event_writer: EventWriter<EmitParticles>,
+) {
+    for impact in impact_event_reader.read() {
+        for (body, other) in [
+            (impact.body_1, impact.body_2),
+            (impact.body_2, impact.body_1),
+        ] {
+            if impact.speed >= DUST_MIN_SPEED
+                && ground_query.contains(body)
+                && falling_query.contains(other)
+            {
+                emit_event_writer.send(EmitParticles {
+                    effect: ParticleEffect::Dust,
+                    position: impact.point,
+                    intensity: normalized(impact.speed, DUST_MIN_SPEED, DUST_MAX_SPEED),
+                });
+            }
+
+            // A hard landing splashes some water out around the bottle.
+            if impact.speed >= SPLASH_MIN_SPEED && bottle_query.contains(body) {
+                emit_event_writer.send(EmitParticles {
+                    effect: ParticleEffect::WaterDroplets,
+                    position: impact.point,
+                    intensity: normalized(impact.speed, SPLASH_MIN_SPEED, SPLASH_MAX_SPEED),
+                });
+            }
+
+            if impact.speed >= DEBRIS_MIN_SPEED
+                && matches!(surface_query.get(body), Ok(Surface::Cardboard))
+            {
+                emit_event_writer.send(EmitParticles {
+                    effect: ParticleEffect::CardboardDebris,
+                    position: impact.point,
+                    intensity: normalized(impact.speed, DEBRIS_MIN_SPEED, DEBRIS_MAX_SPEED),
+                });
+            }
+        }
+    }
+}
+
+pub fn emit_break_particles(
+    mut damaged_event_reader: EventReader<PropDamaged>,
+    mut emit_event_writer: EventWriter<EmitParticles>,
+) {
+    for damaged in damaged_event_reader.read() {
+        let effects: &[ParticleEffect] = match damaged.material {
+            BreakMaterial::Cardboard => &[ParticleEffect::CardboardDebris],
+            // Whatever was in the bottle goes everywhere too.
+            BreakMaterial::Glass => &[ParticleEffect::Shards, ParticleEffect::WaterDroplets],
+            BreakMaterial::Ceramic => &[ParticleEffect::Shards],
+        };
+
+        for effect in effects {
+            emit_event_writer.send(EmitParticles {
+                effect: *effect,
+                position: damaged.position,
+                intensity: if damaged.broken { 1. } else { 0.4 },
+            });
+        }
+    }
+}
+
+pub fn emit_spill_particles(
+    mut spilled_event_reader: EventReader<ContentSpilled>,
+    mut emit_event_writer: EventWriter<EmitParticles>,
+) {
+    for spilled in spilled_event_reader.read() {
+        emit_event_writer.send(EmitParticles {
+            effect: ParticleEffect::WaterDroplets,
+            position: spilled.position,
+            intensity: 1.,
+        });
+    }
+}
+
+pub fn spawn_particles(
+    mut commands: Commands,
+    particle_effects_handle: Res<ParticleEffectsHandle>,
+    particle_effects: Res<Assets<ParticleEffects>>,
+    mut particle_rng: ResMut<ParticleRng>,
+    mut emit_event_reader: EventReader<EmitParticles>,
+) {
+    let Some(particle_effects) = particle_effects.get(&particle_effects_handle.0) else {
+        emit_event_reader.clear();
+        return;
+    };
+
+    let rng = &mut particle_rng.0;
+    for emit in emit_event_reader.read() {
+        let config = match emit.effect {
+            ParticleEffect::Dust => &particle_effects.dust,
+            ParticleEffect::CardboardDebris => &particle_effects.cardboard_debris,
+            ParticleEffect::WaterDroplets => &particle_effects.water_droplets,
+            ParticleEffect::Shards => &particle_effects.shards,
+        };
+
+        let count = (config.count as f32 * emit.intensity.clamp(0., 1.)).ceil() as u32;
+        for _ in 0..count {
+            let angle = (config.angle + rng.range(-0.5, 0.5) * config.spread).to_radians();
+            let [red, green, blue, alpha] = match config.colors.len() {
+                0 => [1., 1., 1., 1.],
+                len => config.colors[(rng.next_u64() % len as u64) as usize],
+            };
+            let size = rng.range(config.size.0, config.size.1);
+
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::srgba(red, green, blue, alpha),
+                        custom_size: Some(Vec2::splat(size)),
+                        ..default()
+                    },
+                    transform: Transform::from_translation(emit.position.extend(PARTICLE_Z))
+                        .with_rotation(Quat::from_rotation_z(rng.range(0., TAU))),
+                    ..default()
+                },
+                Particle {
+                    velocity: Vec2::from_angle(angle) * rng.range(config.speed.0, config.speed.1),
+                    age: 0.,
+                    lifetime: rng.range(config.lifetime.0, config.lifetime.1),
+                    gravity: config.gravity,
+                    drag: config.drag,
+                    size,
+                    end_size: size * config.end_size_scale,
+                    alpha,
+                    end_alpha: config.end_alpha,
+                    spin: rng.range(-config.spin, config.spin),
+                },
+            ));
+        }
+    }
+}
+
+pub fn update_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut particle_query: Query<(Entity, &mut Particle, &mut Transform, &mut Sprite)>,
+) {
+    let delta_seconds = time.delta_seconds();
+
+    for (entity, mut particle, mut transform, mut sprite) in &mut particle_query {
+        particle.age += delta_seconds;
+        if particle.age >= particle.lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let drag = 1. / (1. + particle.drag * delta_seconds);
+        particle.velocity.y -= particle.gravity * delta_seconds;
+        particle.velocity *= drag;
+
+        transform.translation += (particle.velocity * delta_seconds).extend(0.);
+        transform.rotate_z(particle.spin * delta_seconds);
+
+        let t = particle.age / particle.lifetime;
+        sprite.custom_size = Some(Vec2::splat(lerp(particle.size, particle.end_size, t)));
+        sprite
+            .color
+            .set_alpha(lerp(particle.alpha, particle.end_alpha, t));
+    }
+}
@@ -0,0 +1,4 @@
+use bevy::prelude::*;
+
+#[derive(Component, Default)]
+pub struct PreviousVelocity(pub Vec2);
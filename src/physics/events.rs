@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+#[derive(Event, Clone, Copy)]
+pub struct Impact {
+    pub body_1: Entity,
+    pub body_2: Entity,
+    pub point: Vec2,
+    pub speed: f32,
+}
+
+impl Impact {
+    pub fn other(&self, body: Entity) -> Option<Entity> {
+        if body == self.body_1 {
+            Some(self.body_2)
+        } else if body == self.body_2 {
+            Some(self.body_1)
+        } else {
+            None
+        }
+    }
+}
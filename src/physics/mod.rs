@@ -1,8 +1,46 @@
+pub mod components;
+pub mod events;
+mod systems;
+
+use crate::physics::events::Impact;
+use crate::physics::systems::{detect_impacts, track_previous_velocity};
 use avian2d::prelude::*;
+use bevy::prelude::*;
+
+// Pixels per meter.
+pub const LENGTH_UNIT: f32 = 100.;
+// The physics clock steps at this rate, whatever the frame rate, so the same
+// throw plays out the same way on slow and fast machines.
+pub const TIMESTEP_HZ: f64 = 64.;
 
 #[derive(PhysicsLayer)]
 pub enum CustomCollisionLayer {
     Bottle,  // Layer 0
     Content, // Layer 1
     Platform,
+    // Sensors that only notice the bottle.
+    Target,
+}
+
+// Turns each physics step's collisions into impacts, for systems that react to
+// them after the same step.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ImpactDetection;
+
+pub struct ImpactsPlugin;
+
+impl Plugin for ImpactsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Impact>();
+
+        // Once per physics step, so no collision is missed and the speed is
+        // the one from just before the hit, however many steps a frame takes.
+        app.add_systems(
+            PhysicsSchedule,
+            (detect_impacts, track_previous_velocity)
+                .chain()
+                .after(PhysicsStepSet::Last)
+                .in_set(ImpactDetection),
+        );
+    }
 }
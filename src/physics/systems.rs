@@ -0,0 +1,84 @@
+use crate::physics::components::PreviousVelocity;
+use crate::physics::events::Impact;
+use crate::physics::CustomCollisionLayer;
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+pub fn detect_impacts(
+    collisions: Res<Collisions>,
+    mut collision_event_reader: EventReader<CollisionStarted>,
+    collider_query: Query<(&CollisionLayers, &ColliderParent, &Position, &Rotation)>,
+    body_query: Query<Option<&PreviousVelocity>>,
+    mut impact_event_writer: EventWriter<Impact>,
+) {
+    // Compound bodies like the bottle can start contacts for several of their
+    // colliders at once, so only the first one per pair of bodies counts.
+    let mut impacts = HashSet::new();
+    let is_solid = |layers: &CollisionLayers| {
+        layers.memberships.has_all(CustomCollisionLayer::Bottle)
+            || layers.memberships.has_all(CustomCollisionLayer::Platform)
+    };
+
+    for CollisionStarted(entity_1, entity_2) in collision_event_reader.read() {
+        let Ok([collider_1, collider_2]) = collider_query.get_many([*entity_1, *entity_2]) else {
+            continue;
+        };
+        let (layers_1, parent_1, position_1, rotation_1) = collider_1;
+        let (layers_2, parent_2, position_2, rotation_2) = collider_2;
+
+        if !is_solid(layers_1) || !is_solid(layers_2) {
+            continue;
+        }
+
+        let (body_1, body_2) = (parent_1.get(), parent_2.get());
+        if !impacts.insert((body_1.min(body_2), body_1.max(body_2))) {
+            continue;
+        }
+
+        let point = collisions
+            .get(*entity_1, *entity_2)
+            .and_then(|contacts| {
+                let contact = contacts.manifolds.first()?.contacts.first()?;
+                Some(if contacts.entity1 == *entity_1 {
+                    contact.global_point1(position_1, rotation_1)
+                } else {
+                    contact.global_point1(position_2, rotation_2)
+                })
+            })
+            .unwrap_or((position_1.0 + position_2.0) / 2.);
+
+        let previous_velocity = |body: Entity| {
+            body_query
+                .get(body)
+                .ok()
+                .flatten()
+                .map_or(Vec2::ZERO, |velocity| velocity.0)
+        };
+
+        impact_event_writer.send(Impact {
+            body_1,
+            body_2,
+            point,
+            speed: (previous_velocity(body_1) - previous_velocity(body_2)).length(),
+        });
+    }
+}
+
+pub fn track_previous_velocity(
+    mut commands: Commands,
+    mut tracked_query: Query<(&LinearVelocity, &mut PreviousVelocity)>,
+    untracked_query: Query<(Entity, &RigidBody, &LinearVelocity), Without<PreviousVelocity>>,
+) {
+    for (linear_velocity, mut previous_velocity) in &mut tracked_query {
+        previous_velocity.0 = linear_velocity.0;
+    }
+
+    for (entity, rigid_body, linear_velocity) in &untracked_query {
+        if rigid_body.is_dynamic() {
+            commands
+                .entity(entity)
+                .insert(PreviousVelocity(linear_velocity.0));
+        }
+    }
+}
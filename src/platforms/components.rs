@@ -1,4 +1,72 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 #[derive(Component)]
 pub struct DynamicPlatform;
+
+#[derive(Component)]
+pub struct Ground;
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Surface {
+    Wood,
+    Cardboard,
+    Metal,
+    Ceramic,
+}
+
+#[derive(Component, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PlatformKind {
+    CardboardBox,
+    TrashCan,
+    Shelf,
+    Duck,
+    // A stack of plates, each of which smashes when hit hard.
+    Plates,
+}
+
+impl PlatformKind {
+    pub fn key(self) -> &'static str {
+        match self {
+            PlatformKind::CardboardBox => "platform_box",
+            PlatformKind::TrashCan => "platform_trash_can",
+            PlatformKind::Shelf => "platform_shelf",
+            PlatformKind::Duck => "platform_duck",
+            PlatformKind::Plates => "platform_plates",
+        }
+    }
+}
+
+// A bullseye on a platform's top face or on the floor, scoring more the closer
+// to its centre the bottle comes to rest.
+#[derive(Component, Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub struct TargetZone {
+    // Along the surface from the platform's centre, or from the middle of the floor.
+    #[serde(default)]
+    pub offset: f32,
+    pub radius: f32,
+    #[serde(default = "default_rings")]
+    pub rings: u32,
+    // For the innermost ring, each ring out is worth a step less.
+    pub points: u32,
+}
+
+fn default_rings() -> u32 {
+    3
+}
+
+impl TargetZone {
+    pub fn score(&self, distance: f32) -> u32 {
+        let rings = self.rings.max(1);
+        if distance > self.radius {
+            return 0;
+        }
+
+        let ring = ((distance / self.radius * rings as f32) as u32).min(rings - 1);
+        self.points * (rings - ring) / rings
+    }
+}
+
+// Target zones on the floor, which stays while levels come and go.
+#[derive(Component)]
+pub struct GroundTarget;
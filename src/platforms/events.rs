@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+// The bottle came to rest in a target zone.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TargetHit {
+    pub points: u32,
+    pub position: Vec2,
+}
@@ -1,13 +1,26 @@
 pub mod components;
+pub mod events;
 pub mod systems;
 
-use crate::platforms::systems::{spawn_dynamic_platforms, spawn_ground};
+use crate::bottle::systems::detect_bottle_landing;
+use crate::game::{GameState, Scoring};
+use crate::platforms::events::TargetHit;
+use crate::platforms::systems::{detect_target_hits, spawn_ground};
 use bevy::prelude::*;
 
 pub struct PlatformsPlugin;
 
 impl Plugin for PlatformsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, (spawn_ground, spawn_dynamic_platforms));
+        app.add_event::<TargetHit>();
+
+        app.add_systems(Startup, spawn_ground);
+        app.add_systems(
+            Update,
+            detect_target_hits
+                .after(detect_bottle_landing)
+                .before(Scoring)
+                .run_if(in_state(GameState::Playing)),
+        );
     }
 }
@@ -1,13 +1,47 @@
+use crate::bottle::components::Bottle;
+use crate::bottle::events::BottleLanded;
+use crate::bottle::systems::BOTTLE_BODY_SIZE;
+use crate::breakables::components::{BreakMaterial, Breakable};
+use crate::levels::assets::PlatformSpec;
 use crate::physics::CustomCollisionLayer;
-use crate::platforms::components::DynamicPlatform;
+use crate::platforms::components::{
+    DynamicPlatform, Ground, GroundTarget, PlatformKind, Surface, TargetZone,
+};
+use crate::platforms::events::TargetHit;
 use avian2d::prelude::*;
+use bevy::ecs::system::EntityCommands;
 use bevy::prelude::*;
 use bevy::sprite::{MaterialMesh2dBundle, Mesh2dHandle};
 
-// const TRASH_CAN_SIZE: Vec2 = Vec2::new(260., 320.);
-// const TRASH_CAN_LID_SIZE: Vec2 = Vec2::new(296., 30.);
+pub const CARDBOARD_BOX_SIZE: Vec2 = Vec2::new(230., 145.);
+pub const TRASH_CAN_SIZE: Vec2 = Vec2::new(150., 188.);
+const TRASH_CAN_LID_SIZE: Vec2 = Vec2::new(172., 24.);
+pub const SHELF_SIZE: Vec2 = Vec2::new(160., 16.);
+const SHELF_COLOR: Color = Color::srgb(0.545, 0.361, 0.259);
+pub const DUCK_SIZE: Vec2 = Vec2::new(60., 60.);
+const DUCK_SPRITE_SCALE: f32 = 0.3;
+const DUCK_DENSITY: f32 = 0.5;
+const TRASH_CAN_DENSITY: f32 = 4.;
+const CARDBOARD_BOX_BREAK_SPEED: f32 = 600.;
+const CARDBOARD_BOX_HEALTH: u32 = 4;
+const PLATE_SIZE: Vec2 = Vec2::new(120., 12.);
+const PLATE_COUNT: u32 = 4;
+pub const PLATES_SIZE: Vec2 = Vec2::new(PLATE_SIZE.x, PLATE_SIZE.y * PLATE_COUNT as f32);
+const PLATE_COLOR: Color = Color::srgb(0.961, 0.953, 0.929);
+const PLATE_BREAK_SPEED: f32 = 400.;
 
-const FLOOR_LEVEL: f32 = -160.;
+// Tall enough to reach the base of a bottle resting on the surface.
+const TARGET_SENSOR_HEIGHT: f32 = 16.;
+const TARGET_DECAL_HEIGHT: f32 = 6.;
+const TARGET_DECAL_Z: f32 = 0.5;
+const TARGET_COLORS: [Color; 2] = [
+    Color::srgb(0.851, 0.267, 0.267),
+    Color::srgb(0.98, 0.96, 0.92),
+];
+
+pub const FLOOR_LEVEL: f32 = -160.;
+// Used when there's no window, like in headless simulations.
+const DEFAULT_VIEW_SIZE: Vec2 = Vec2::new(740., 360.);
 
 pub fn spawn_ground(
     mut commands: Commands,
@@ -15,9 +49,11 @@ pub fn spawn_ground(
     mut materials: ResMut<Assets<ColorMaterial>>,
     windows: Query<&Window>,
 ) {
-    let window = windows.single();
+    let view_size = windows
+        .get_single()
+        .map_or(DEFAULT_VIEW_SIZE, |window| window.size());
 
-    let floor_size = Vec2::new(window.size().x, window.size().y / 2. + FLOOR_LEVEL);
+    let floor_size = Vec2::new(view_size.x, view_size.y / 2. + FLOOR_LEVEL);
 
     commands
         .spawn((
@@ -29,6 +65,8 @@ pub fn spawn_ground(
                 CustomCollisionLayer::Platform,
                 [CustomCollisionLayer::Bottle, CustomCollisionLayer::Platform],
             ),
+            Surface::Wood,
+            Ground,
         ))
         .with_children(|child_builder| {
             child_builder.spawn(MaterialMesh2dBundle {
@@ -40,22 +78,146 @@ pub fn spawn_ground(
         });
 }
 
-pub fn spawn_dynamic_platforms(mut commands: Commands, asset_server: Res<AssetServer>) {
-    spawn_cardboard_box(&mut commands, &asset_server);
+// Returns the bodies the platform is made of, the one with its top face last.
+pub fn spawn_platform(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    platform: &PlatformSpec,
+) -> Vec<Entity> {
+    let transform = Transform::from_translation(platform.position.extend(0.))
+        .with_rotation(Quat::from_rotation_z(platform.rotation))
+        .with_scale(platform.scale.extend(1.));
+
+    let entities = match platform.kind {
+        PlatformKind::CardboardBox => vec![spawn_cardboard_box(commands, asset_server, transform)],
+        PlatformKind::TrashCan => vec![spawn_trash_can(commands, asset_server, transform)],
+        PlatformKind::Shelf => vec![spawn_shelf(commands, transform)],
+        PlatformKind::Duck => vec![spawn_duck(commands, asset_server, transform)],
+        PlatformKind::Plates => spawn_plates(commands, transform),
+    };
+
+    if let (Some(target), Some(top)) = (platform.target, entities.last()) {
+        commands.entity(*top).with_children(|child_builder| {
+            spawn_target_zone(child_builder, target, top_surface(platform.kind));
+        });
+    }
+
+    entities
+}
+
+// Height of a platform's top face above its centre, before scaling.
+fn top_surface(kind: PlatformKind) -> f32 {
+    match kind {
+        PlatformKind::CardboardBox => CARDBOARD_BOX_SIZE.y / 2.,
+        PlatformKind::TrashCan => TRASH_CAN_SIZE.y / 2. + TRASH_CAN_LID_SIZE.y,
+        PlatformKind::Shelf => SHELF_SIZE.y / 2.,
+        PlatformKind::Duck => DUCK_SIZE.y / 2.,
+        // Plates are spawned bottom up, so this is the top plate.
+        PlatformKind::Plates => PLATE_SIZE.y / 2.,
+    }
+}
+
+// A sensor just above the surface at `surface` height, and a strip of rings
+// painted just below it.
+pub fn spawn_target_zone<'a>(
+    child_builder: &'a mut ChildBuilder,
+    target: TargetZone,
+    surface: f32,
+) -> EntityCommands<'a> {
+    let mut entity_commands = child_builder.spawn((
+        VisibilityBundle::default(),
+        TransformBundle::from_transform(Transform::from_xyz(
+            target.offset,
+            surface + TARGET_SENSOR_HEIGHT / 2.,
+            0.,
+        )),
+        Collider::rectangle(target.radius * 2., TARGET_SENSOR_HEIGHT),
+        ColliderDensity(0.),
+        Sensor,
+        CollisionLayers::new(CustomCollisionLayer::Target, [CustomCollisionLayer::Bottle]),
+        target,
+    ));
+
+    entity_commands.with_children(|child_builder| {
+        let rings = target.rings.max(1);
+        for ring in 0..rings {
+            let width = target.radius * 2. * (rings - ring) as f32 / rings as f32;
+            child_builder.spawn(SpriteBundle {
+                sprite: Sprite {
+                    color: TARGET_COLORS[ring as usize % TARGET_COLORS.len()],
+                    custom_size: Some(Vec2::new(width, TARGET_DECAL_HEIGHT)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(
+                    0.,
+                    -(TARGET_SENSOR_HEIGHT + TARGET_DECAL_HEIGHT) / 2.,
+                    TARGET_DECAL_Z + ring as f32 * 0.01,
+                ),
+                ..default()
+            });
+        }
+    });
+
+    entity_commands
+}
+
+pub fn spawn_ground_targets(commands: &mut Commands, ground: Entity, targets: &[TargetZone]) {
+    commands.entity(ground).with_children(|child_builder| {
+        for target in targets {
+            spawn_target_zone(child_builder, *target, 0.).insert(GroundTarget);
+        }
+    });
+}
+
+// A plank fixed to the wall, so it stays put when hit.
+pub fn spawn_shelf(commands: &mut Commands, transform: Transform) -> Entity {
+    commands
+        .spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: SHELF_COLOR,
+                    custom_size: Some(SHELF_SIZE),
+                    ..default()
+                },
+                transform,
+                ..default()
+            },
+            RigidBody::Static,
+            Collider::rectangle(SHELF_SIZE.x, SHELF_SIZE.y),
+            CollisionLayers::new(
+                CustomCollisionLayer::Platform,
+                [CustomCollisionLayer::Bottle, CustomCollisionLayer::Platform],
+            ),
+            Surface::Wood,
+            PlatformKind::Shelf,
+        ))
+        .id()
 }
 
-pub fn spawn_cardboard_box(commands: &mut Commands, asset_server: &Res<AssetServer>) {
+pub fn spawn_cardboard_box(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    transform: Transform,
+) -> Entity {
     commands
         .spawn((
             VisibilityBundle::default(),
-            TransformBundle::from_transform(Transform::from_xyz(200., 40., 0.)),
+            TransformBundle::from_transform(transform),
             RigidBody::Dynamic,
-            Collider::rectangle(230., 145.),
+            Collider::rectangle(CARDBOARD_BOX_SIZE.x, CARDBOARD_BOX_SIZE.y),
             CollisionLayers::new(
                 CustomCollisionLayer::Platform,
                 [CustomCollisionLayer::Bottle, CustomCollisionLayer::Platform],
             ),
             DynamicPlatform,
+            Surface::Cardboard,
+            PlatformKind::CardboardBox,
+            Breakable {
+                material: BreakMaterial::Cardboard,
+                threshold: CARDBOARD_BOX_BREAK_SPEED,
+                health: CARDBOARD_BOX_HEALTH,
+                size: CARDBOARD_BOX_SIZE,
+            },
         ))
         .with_children(|child_builder| {
             child_builder.spawn(SpriteBundle {
@@ -63,5 +225,185 @@ pub fn spawn_cardboard_box(commands: &mut Commands, asset_server: &Res<AssetServ
                 transform: Transform::from_xyz(0., 0., 0.).with_scale(Vec3::ONE * 4.),
                 ..default()
             });
-        });
+        })
+        .id()
+}
+
+pub fn spawn_trash_can(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    transform: Transform,
+) -> Entity {
+    commands
+        .spawn((
+            VisibilityBundle::default(),
+            TransformBundle::from_transform(transform),
+            RigidBody::Dynamic,
+            ColliderDensity(TRASH_CAN_DENSITY),
+            Collider::rectangle(TRASH_CAN_SIZE.x, TRASH_CAN_SIZE.y),
+            CollisionLayers::new(
+                CustomCollisionLayer::Platform,
+                [CustomCollisionLayer::Bottle, CustomCollisionLayer::Platform],
+            ),
+            DynamicPlatform,
+            Surface::Metal,
+            PlatformKind::TrashCan,
+        ))
+        .with_children(|child_builder| {
+            child_builder.spawn(SpriteBundle {
+                texture: asset_server.load("trash_can.png"),
+                transform: Transform::from_xyz(0., 0., 0.).with_scale(Vec3::ONE * 4.),
+                ..default()
+            });
+
+            // Lid
+            child_builder.spawn(SpriteBundle {
+                texture: asset_server.load("trash_can_lid.png"),
+                transform: Transform::from_xyz(0., TRASH_CAN_SIZE.y / 2. + 18., 0.1)
+                    .with_scale(Vec3::ONE * 4.),
+                ..default()
+            });
+            child_builder.spawn((
+                TransformBundle::from_transform(Transform::from_xyz(
+                    0.,
+                    (TRASH_CAN_SIZE.y + TRASH_CAN_LID_SIZE.y) / 2.,
+                    0.,
+                )),
+                ColliderDensity(TRASH_CAN_DENSITY),
+                Collider::rectangle(TRASH_CAN_LID_SIZE.x, TRASH_CAN_LID_SIZE.y),
+                CollisionLayers::new(
+                    CustomCollisionLayer::Platform,
+                    [CustomCollisionLayer::Bottle, CustomCollisionLayer::Platform],
+                ),
+            ));
+        })
+        .id()
+}
+
+// Loose plates stacked on top of each other, returned bottom up.
+pub fn spawn_plates(commands: &mut Commands, transform: Transform) -> Vec<Entity> {
+    (0..PLATE_COUNT)
+        .map(|index| {
+            let offset = Vec3::Y * ((index as f32 + 0.5) * PLATE_SIZE.y - PLATES_SIZE.y / 2.);
+            commands
+                .spawn((
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: PLATE_COLOR,
+                            custom_size: Some(PLATE_SIZE),
+                            ..default()
+                        },
+                        transform: transform.with_translation(transform.transform_point(offset)),
+                        ..default()
+                    },
+                    RigidBody::Dynamic,
+                    Collider::rectangle(PLATE_SIZE.x, PLATE_SIZE.y),
+                    CollisionLayers::new(
+                        CustomCollisionLayer::Platform,
+                        [CustomCollisionLayer::Bottle, CustomCollisionLayer::Platform],
+                    ),
+                    DynamicPlatform,
+                    Surface::Ceramic,
+                    PlatformKind::Plates,
+                    Breakable {
+                        material: BreakMaterial::Ceramic,
+                        threshold: PLATE_BREAK_SPEED,
+                        health: 1,
+                        size: PLATE_SIZE,
+                    },
+                ))
+                .id()
+        })
+        .collect()
+}
+
+// A rubber duck, light enough to knock around.
+pub fn spawn_duck(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    transform: Transform,
+) -> Entity {
+    commands
+        .spawn((
+            VisibilityBundle::default(),
+            TransformBundle::from_transform(transform),
+            RigidBody::Dynamic,
+            ColliderDensity(DUCK_DENSITY),
+            Collider::circle(DUCK_SIZE.x / 2.),
+            CollisionLayers::new(
+                CustomCollisionLayer::Platform,
+                [CustomCollisionLayer::Bottle, CustomCollisionLayer::Platform],
+            ),
+            DynamicPlatform,
+            Surface::Cardboard,
+            PlatformKind::Duck,
+        ))
+        .with_children(|child_builder| {
+            child_builder.spawn(SpriteBundle {
+                texture: asset_server.load("ducky.png"),
+                transform: Transform::from_scale(Vec3::ONE * DUCK_SPRITE_SCALE),
+                ..default()
+            });
+        })
+        .id()
+}
+
+// Scores the zone under the end the bottle stands on, the best one if it
+// rests across several.
+pub fn detect_target_hits(
+    collisions: Res<Collisions>,
+    bottle_query: Query<(Entity, &Position, &Rotation), With<Bottle>>,
+    collider_query: Query<&ColliderParent>,
+    target_query: Query<(Entity, &TargetZone, &GlobalTransform)>,
+    mut landed_event_reader: EventReader<BottleLanded>,
+    mut target_hit_event_writer: EventWriter<TargetHit>,
+) {
+    for landed in landed_event_reader.read() {
+        if !landed.standing() {
+            continue;
+        }
+
+        let Ok((bottle, position, rotation)) = bottle_query.get_single() else {
+            continue;
+        };
+
+        let up = Vec2::from_angle(rotation.as_radians()).rotate(Vec2::Y);
+        let base = if landed.upright {
+            position.0 - up * BOTTLE_BODY_SIZE.y / 2.
+        } else {
+            position.0 + up * BOTTLE_BODY_SIZE.y / 2.
+        };
+
+        let points = target_query
+            .iter()
+            .filter(|(sensor, _, _)| {
+                collisions.collisions_with_entity(*sensor).any(|contacts| {
+                    let other = if contacts.entity1 == *sensor {
+                        contacts.entity2
+                    } else {
+                        contacts.entity1
+                    };
+                    contacts.during_current_frame
+                        && collider_query
+                            .get(other)
+                            .is_ok_and(|parent| parent.get() == bottle)
+                })
+            })
+            .map(|(_, target, transform)| {
+                let local = transform
+                    .affine()
+                    .inverse()
+                    .transform_point3(base.extend(0.));
+                target.score(local.x.abs())
+            })
+            .max()
+            .unwrap_or_default();
+
+        if points > 0 {
+            target_hit_event_writer.send(TargetHit {
+                points,
+                position: base,
+            });
+        }
+    }
 }
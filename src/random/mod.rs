@@ -0,0 +1,35 @@
+// A small seedable generator (SplitMix64). Unlike `rand`, its output is part of
+// this crate, so seeded sequences stay identical across platforms and releases.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in [0, 1).
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+
+    // Standard normal, by the Box-Muller transform.
+    pub fn normal(&mut self) -> f32 {
+        let radius = (-2. * (1. - self.next_f32()).ln()).sqrt();
+        radius * (std::f32::consts::TAU * self.next_f32()).cos()
+    }
+}
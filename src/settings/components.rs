@@ -0,0 +1,31 @@
+use crate::settings::resources::Binding;
+use bevy::prelude::*;
+
+#[derive(Component, Clone, Copy)]
+pub enum VolumeSlider {
+    Master,
+    Music,
+    Sfx,
+}
+
+#[derive(Component)]
+pub struct MuteToggle;
+
+#[derive(Component, Clone, Copy)]
+pub enum SettingToggle {
+    TrajectoryPreview,
+    PhysicsDebug,
+    Fullscreen,
+}
+
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum SettingButton {
+    Language,
+    Palette,
+    Binding(Binding),
+    Back,
+}
+
+// Text showing the current value of the setting changed by a `SettingButton`.
+#[derive(Component)]
+pub struct SettingValue(pub SettingButton);
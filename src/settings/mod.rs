@@ -0,0 +1,50 @@
+pub mod components;
+pub mod resources;
+mod systems;
+
+use crate::game::GameState;
+use crate::settings::resources::{Rebinding, Settings, SettingsReturnState};
+use crate::settings::systems::{
+    apply_audio_settings, apply_setting_toggles, apply_window_mode, cancel_rebinding,
+    capture_rebinding, close_settings, draw_setting_values, handle_setting_buttons, save_settings,
+    spawn_settings_screen, SETTINGS_KEY,
+};
+use crate::storage;
+use crate::ui::UiInput;
+use bevy::prelude::*;
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(storage::load_ron::<Settings>(SETTINGS_KEY).unwrap_or_default());
+        app.insert_resource(SettingsReturnState(GameState::Title));
+        app.insert_resource(Rebinding::default());
+
+        app.add_systems(OnEnter(GameState::Settings), spawn_settings_screen);
+        app.add_systems(OnExit(GameState::Settings), cancel_rebinding);
+        app.add_systems(
+            Update,
+            (
+                (capture_rebinding, close_settings)
+                    .chain()
+                    .before(UiInput)
+                    .run_if(in_state(GameState::Settings)),
+                (
+                    apply_audio_settings,
+                    apply_setting_toggles,
+                    handle_setting_buttons,
+                    draw_setting_values,
+                )
+                    .chain()
+                    .after(UiInput),
+                apply_window_mode.run_if(resource_changed::<Settings>),
+            ),
+        );
+        app.add_systems(
+            Last,
+            save_settings
+                .run_if(resource_changed::<Settings>.and_then(not(resource_added::<Settings>))),
+        );
+    }
+}
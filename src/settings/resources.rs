@@ -0,0 +1,197 @@
+use crate::game::GameState;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Resource, Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(default)]
+pub struct Settings {
+    pub audio: AudioSettings,
+    pub trajectory_preview: bool,
+    pub physics_debug: bool,
+    pub fullscreen: bool,
+    pub language: Language,
+    pub palette: Palette,
+    pub bindings: InputBindings,
+    // Shown on the leaderboard.
+    pub player_name: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            audio: AudioSettings::default(),
+            trajectory_preview: true,
+            physics_debug: false,
+            fullscreen: false,
+            language: Language::default(),
+            palette: Palette::default(),
+            bindings: InputBindings::default(),
+            player_name: String::from("Player"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(default)]
+pub struct AudioSettings {
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub sfx_volume: f32,
+    pub muted: bool,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            master_volume: 0.8,
+            music_volume: 0.6,
+            sfx_volume: 1.,
+            muted: false,
+        }
+    }
+}
+
+impl AudioSettings {
+    pub fn music_gain(&self) -> f32 {
+        if self.muted {
+            return 0.;
+        }
+
+        self.master_volume * self.music_volume
+    }
+
+    pub fn sfx_gain(&self) -> f32 {
+        if self.muted {
+            return 0.;
+        }
+
+        self.master_volume * self.sfx_volume
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Swedish,
+    German,
+}
+
+impl Language {
+    pub const ALL: [Language; 3] = [Language::English, Language::Swedish, Language::German];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Swedish => "Svenska",
+            Language::German => "Deutsch",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Palette {
+    #[default]
+    Standard,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+}
+
+impl Palette {
+    pub const ALL: [Palette; 4] = [
+        Palette::Standard,
+        Palette::Deuteranopia,
+        Palette::Protanopia,
+        Palette::Tritanopia,
+    ];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            Palette::Standard => "palette_standard",
+            Palette::Deuteranopia => "palette_deuteranopia",
+            Palette::Protanopia => "palette_protanopia",
+            Palette::Tritanopia => "palette_tritanopia",
+        }
+    }
+
+    // Highlights such as focus outlines, filled sliders and enabled toggles.
+    pub fn accent(self) -> Color {
+        match self {
+            Palette::Standard => Color::srgb(0.969, 0.812, 0.569),
+            Palette::Deuteranopia | Palette::Protanopia => Color::srgb(0.902, 0.624, 0.),
+            Palette::Tritanopia => Color::srgb(0.8, 0.475, 0.655),
+        }
+    }
+
+    // Gameplay hints drawn on top of the scene, like the trajectory preview.
+    pub fn hint(self) -> Color {
+        match self {
+            Palette::Standard => Color::WHITE,
+            Palette::Deuteranopia | Palette::Protanopia => Color::srgb(0.337, 0.706, 0.914),
+            Palette::Tritanopia => Color::srgb(0., 0.62, 0.451),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(default)]
+pub struct InputBindings {
+    pub pause: KeyCode,
+    pub restart: KeyCode,
+    pub confirm: KeyCode,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        Self {
+            pause: KeyCode::Escape,
+            restart: KeyCode::KeyR,
+            confirm: KeyCode::Enter,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Binding {
+    Pause,
+    Restart,
+    Confirm,
+}
+
+impl Binding {
+    pub const ALL: [Binding; 3] = [Binding::Pause, Binding::Restart, Binding::Confirm];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            Binding::Pause => "binding_pause",
+            Binding::Restart => "binding_restart",
+            Binding::Confirm => "binding_confirm",
+        }
+    }
+}
+
+impl InputBindings {
+    pub fn key(&self, binding: Binding) -> KeyCode {
+        match binding {
+            Binding::Pause => self.pause,
+            Binding::Restart => self.restart,
+            Binding::Confirm => self.confirm,
+        }
+    }
+
+    pub fn key_mut(&mut self, binding: Binding) -> &mut KeyCode {
+        match binding {
+            Binding::Pause => &mut self.pause,
+            Binding::Restart => &mut self.restart,
+            Binding::Confirm => &mut self.confirm,
+        }
+    }
+}
+
+// The state to go back to when leaving the settings screen.
+#[derive(Resource)]
+pub struct SettingsReturnState(pub GameState);
+
+#[derive(Resource, Default)]
+pub struct Rebinding(pub Option<Binding>);
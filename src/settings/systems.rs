@@ -0,0 +1,317 @@
+use crate::game::GameState;
+use crate::i18n::translate;
+use crate::settings::components::{
+    MuteToggle, SettingButton, SettingToggle, SettingValue, VolumeSlider,
+};
+use crate::settings::resources::{
+    Binding, Language, Palette, Rebinding, Settings, SettingsReturnState,
+};
+use crate::storage;
+use crate::ui::components::{MenuButton, Slider, Toggle};
+use crate::ui::events::ButtonActivated;
+use crate::ui::widgets::{
+    spawn_label, spawn_menu_button, spawn_row, spawn_screen, spawn_slider, spawn_text,
+    spawn_toggle, text_style, FONT_SIZE, PANEL_COLOR,
+};
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowMode};
+
+pub const SETTINGS_KEY: &str = "settings";
+const SCREEN_COLOR: Color = Color::srgba(0.176, 0.114, 0.192, 0.9);
+const HEADING_FONT_SIZE: f32 = 32.;
+
+pub fn save_settings(settings: Res<Settings>) {
+    storage::save_ron(SETTINGS_KEY, settings.as_ref());
+}
+
+fn next<T: Copy + PartialEq>(all: &[T], current: T) -> T {
+    let index = all
+        .iter()
+        .position(|value| *value == current)
+        .unwrap_or_default();
+    all[(index + 1) % all.len()]
+}
+
+fn key_name(key: KeyCode) -> String {
+    let name = format!("{key:?}");
+    name.trim_start_matches("Key").to_string()
+}
+
+fn spawn_column(child_builder: &mut ChildBuilder, spawn_children: impl FnOnce(&mut ChildBuilder)) {
+    child_builder
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Px(300.),
+                padding: UiRect::all(Val::Px(12.)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.),
+                ..default()
+            },
+            background_color: PANEL_COLOR.into(),
+            ..default()
+        })
+        .with_children(spawn_children);
+}
+
+fn spawn_value_button(child_builder: &mut ChildBuilder, setting_button: SettingButton) {
+    child_builder
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    min_width: Val::Px(120.),
+                    padding: UiRect::axes(Val::Px(8.), Val::Px(2.)),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                background_color: SCREEN_COLOR.into(),
+                ..default()
+            },
+            Outline::new(Val::Px(2.), Val::ZERO, Color::NONE),
+            MenuButton,
+            setting_button,
+        ))
+        .with_children(|child_builder| {
+            child_builder.spawn((
+                TextBundle::from_section("", text_style(FONT_SIZE)),
+                SettingValue(setting_button),
+            ));
+        });
+}
+
+pub fn spawn_settings_screen(mut commands: Commands, settings: Res<Settings>) {
+    spawn_screen(
+        &mut commands,
+        SCREEN_COLOR,
+        StateScoped(GameState::Settings),
+        |child_builder| {
+            spawn_text(child_builder, "settings", text_style(HEADING_FONT_SIZE));
+
+            child_builder
+                .spawn(NodeBundle {
+                    style: Style {
+                        column_gap: Val::Px(12.),
+                        align_items: AlignItems::Start,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|child_builder| {
+                    spawn_column(child_builder, |child_builder| {
+                        let audio = &settings.audio;
+                        for (label, value, slider) in [
+                            ("master_volume", audio.master_volume, VolumeSlider::Master),
+                            ("music_volume", audio.music_volume, VolumeSlider::Music),
+                            ("sfx_volume", audio.sfx_volume, VolumeSlider::Sfx),
+                        ] {
+                            spawn_row(child_builder, |child_builder| {
+                                spawn_label(child_builder, label);
+                                spawn_slider(child_builder, value, slider);
+                            });
+                        }
+
+                        spawn_row(child_builder, |child_builder| {
+                            spawn_label(child_builder, "mute");
+                            spawn_toggle(child_builder, audio.muted, MuteToggle);
+                        });
+
+                        for (label, on, toggle) in [
+                            (
+                                "trajectory_preview",
+                                settings.trajectory_preview,
+                                SettingToggle::TrajectoryPreview,
+                            ),
+                            (
+                                "physics_debug",
+                                settings.physics_debug,
+                                SettingToggle::PhysicsDebug,
+                            ),
+                            ("fullscreen", settings.fullscreen, SettingToggle::Fullscreen),
+                        ] {
+                            // The overlay only exists in builds with the `debug` feature.
+                            if matches!(toggle, SettingToggle::PhysicsDebug)
+                                && cfg!(not(feature = "debug"))
+                            {
+                                continue;
+                            }
+
+                            spawn_row(child_builder, |child_builder| {
+                                spawn_label(child_builder, label);
+                                spawn_toggle(child_builder, on, toggle);
+                            });
+                        }
+                    });
+
+                    spawn_column(child_builder, |child_builder| {
+                        for (label, setting_button) in [
+                            ("language", SettingButton::Language),
+                            ("palette", SettingButton::Palette),
+                        ] {
+                            spawn_row(child_builder, |child_builder| {
+                                spawn_label(child_builder, label);
+                                spawn_value_button(child_builder, setting_button);
+                            });
+                        }
+
+                        for binding in Binding::ALL {
+                            spawn_row(child_builder, |child_builder| {
+                                spawn_label(child_builder, binding.key());
+                                spawn_value_button(child_builder, SettingButton::Binding(binding));
+                            });
+                        }
+                    });
+                });
+
+            spawn_menu_button(child_builder, "back", true, SettingButton::Back);
+        },
+    );
+}
+
+pub fn apply_audio_settings(
+    mut settings: ResMut<Settings>,
+    slider_query: Query<(&Slider, &VolumeSlider), Changed<Slider>>,
+    toggle_query: Query<&Toggle, (Changed<Toggle>, With<MuteToggle>)>,
+) {
+    for (slider, volume_slider) in &slider_query {
+        let volume = match volume_slider {
+            VolumeSlider::Master => &mut settings.audio.master_volume,
+            VolumeSlider::Music => &mut settings.audio.music_volume,
+            VolumeSlider::Sfx => &mut settings.audio.sfx_volume,
+        };
+
+        if *volume != slider.value {
+            *volume = slider.value;
+        }
+    }
+
+    for toggle in &toggle_query {
+        if settings.audio.muted != toggle.on {
+            settings.audio.muted = toggle.on;
+        }
+    }
+}
+
+pub fn apply_setting_toggles(
+    mut settings: ResMut<Settings>,
+    toggle_query: Query<(&Toggle, &SettingToggle), Changed<Toggle>>,
+) {
+    for (toggle, setting_toggle) in &toggle_query {
+        let value = match setting_toggle {
+            SettingToggle::TrajectoryPreview => &mut settings.trajectory_preview,
+            SettingToggle::PhysicsDebug => &mut settings.physics_debug,
+            SettingToggle::Fullscreen => &mut settings.fullscreen,
+        };
+
+        if *value != toggle.on {
+            *value = toggle.on;
+        }
+    }
+}
+
+pub fn handle_setting_buttons(
+    mut settings: ResMut<Settings>,
+    mut rebinding: ResMut<Rebinding>,
+    settings_return_state: Res<SettingsReturnState>,
+    button_query: Query<&SettingButton>,
+    mut activated_event_reader: EventReader<ButtonActivated>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for ButtonActivated(button) in activated_event_reader.read() {
+        let Ok(setting_button) = button_query.get(*button) else {
+            continue;
+        };
+
+        match *setting_button {
+            SettingButton::Language => {
+                settings.language = next(&Language::ALL, settings.language);
+            }
+            SettingButton::Palette => {
+                settings.palette = next(&Palette::ALL, settings.palette);
+            }
+            SettingButton::Binding(binding) => rebinding.0 = Some(binding),
+            SettingButton::Back => next_state.set(settings_return_state.0),
+        }
+    }
+}
+
+pub fn capture_rebinding(
+    mut settings: ResMut<Settings>,
+    mut rebinding: ResMut<Rebinding>,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+) {
+    let Some(binding) = rebinding.0 else {
+        return;
+    };
+
+    let Some(key) = keys.get_just_pressed().next().copied() else {
+        return;
+    };
+
+    // Keep the key from also navigating or activating something this frame.
+    keys.clear_just_pressed(key);
+    *settings.bindings.key_mut(binding) = key;
+    rebinding.0 = None;
+}
+
+pub fn close_settings(
+    settings: Res<Settings>,
+    rebinding: Res<Rebinding>,
+    settings_return_state: Res<SettingsReturnState>,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let pressed = keys.just_pressed(settings.bindings.pause)
+        || gamepads.iter().any(|gamepad| {
+            gamepad_buttons.just_pressed(GamepadButton::new(gamepad, GamepadButtonType::East))
+        });
+
+    if pressed && rebinding.0.is_none() {
+        next_state.set(settings_return_state.0);
+    }
+}
+
+pub fn cancel_rebinding(mut rebinding: ResMut<Rebinding>) {
+    rebinding.0 = None;
+}
+
+pub fn draw_setting_values(
+    settings: Res<Settings>,
+    rebinding: Res<Rebinding>,
+    mut value_query: Query<(Ref<SettingValue>, &mut Text)>,
+) {
+    for (setting_value, mut text) in &mut value_query {
+        if !settings.is_changed() && !rebinding.is_changed() && !setting_value.is_added() {
+            continue;
+        }
+
+        text.sections[0].value = match setting_value.0 {
+            SettingButton::Language => settings.language.name().to_string(),
+            SettingButton::Palette => {
+                translate(settings.language, settings.palette.key()).to_string()
+            }
+            SettingButton::Binding(binding) if rebinding.0 == Some(binding) => {
+                translate(settings.language, "press_a_key").to_string()
+            }
+            SettingButton::Binding(binding) => key_name(settings.bindings.key(binding)),
+            SettingButton::Back => String::new(),
+        };
+    }
+}
+
+pub fn apply_window_mode(
+    settings: Res<Settings>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let mode = if settings.fullscreen {
+        WindowMode::BorderlessFullscreen
+    } else {
+        WindowMode::Windowed
+    };
+
+    for mut window in &mut window_query {
+        if window.mode != mode {
+            window.mode = mode;
+        }
+    }
+}
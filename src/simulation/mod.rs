@@ -0,0 +1,406 @@
+// Windowless app setup for running the game's physics outside of the game
+// itself, like in tests.
+
+use crate::bottle::components::{Bottle, BottleContent, LooseCap};
+use crate::bottle::events::{BottleLanded, BottleThrown, ImplausibleThrow};
+use crate::bottle::systems::{
+    spawn_bottle, BOTTLE_BODY_SIZE, GRAB_REACH, PLAUSIBLE_ANGULAR_SPEED, PLAUSIBLE_LINEAR_SPEED,
+};
+use crate::bottle::BottlePlugin;
+use crate::breakables::events::BottleShattered;
+use crate::breakables::BreakablesPlugin;
+use crate::forces::ForcesPlugin;
+use crate::game::resources::Round;
+use crate::game::GameState;
+use crate::levels::assets::Level;
+use crate::levels::events::StartLevel;
+use crate::levels::resources::Levels;
+use crate::levels::LevelsPlugin;
+use crate::physics::{ImpactsPlugin, LENGTH_UNIT, TIMESTEP_HZ};
+use crate::platforms::events::TargetHit;
+use crate::platforms::PlatformsPlugin;
+use crate::settings::resources::Settings;
+use crate::tricks::events::TricksLanded;
+use crate::tricks::TricksPlugin;
+use crate::tuning::assets::PhysicsTuning;
+use crate::tuning::TuningPlugin;
+use avian2d::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::gizmos::GizmoPlugin;
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::render::render_resource::Shader;
+use bevy::scene::ScenePlugin;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// Longer than any throw takes to come to rest.
+const MAX_THROW_STEPS: usize = TIMESTEP_HZ as usize * 15;
+// How far from where it rested a bottle can be let go of: carried as far as
+// the grab reaches, and turned about the grabbed point on the way.
+const RELEASE_REACH: f32 = GRAB_REACH + BOTTLE_BODY_SIZE.y + BOTTLE_BODY_SIZE.x;
+
+pub fn headless_app() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        HierarchyPlugin,
+        AssetPlugin::default(),
+        ScenePlugin,
+        StatesPlugin,
+        InputPlugin,
+    ));
+
+    // Assets the game's spawn code and gizmos refer to, without a renderer.
+    app.init_asset::<Image>()
+        .init_asset::<Mesh>()
+        .init_asset::<ColorMaterial>()
+        .init_asset::<TextureAtlasLayout>()
+        .init_asset::<Shader>();
+    app.add_plugins(GizmoPlugin);
+
+    // Every update advances time by exactly one physics step, as the physics
+    // clock steps at the same rate the game uses.
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+        1. / TIMESTEP_HZ,
+    )));
+    app.insert_resource(Time::new_with(Physics::fixed_hz(TIMESTEP_HZ)));
+
+    app.add_plugins(PhysicsPlugins::default().with_length_unit(LENGTH_UNIT));
+    app.insert_resource(Settings::default());
+    app.init_state::<GameState>();
+    app.add_plugins((
+        TuningPlugin { load_config: false },
+        BottlePlugin,
+        PlatformsPlugin,
+        ImpactsPlugin,
+        LevelsPlugin,
+        TricksPlugin,
+        ForcesPlugin,
+        BreakablesPlugin,
+    ));
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Playing);
+
+    // Runs startup systems and enters the playing state.
+    app.update();
+
+    app
+}
+
+pub fn spawn_bottle_at(app: &mut App, position: Vec2) {
+    app.world_mut().run_system_once_with(
+        position,
+        |In(position): In<Vec2>,
+         mut commands: Commands,
+         asset_server: Res<AssetServer>,
+         physics_tuning: Res<PhysicsTuning>| {
+            spawn_bottle(
+                &mut commands,
+                &asset_server,
+                &physics_tuning,
+                position,
+                false,
+                false,
+            );
+        },
+    );
+}
+
+// Replaces the loaded levels with just this one and starts it, the same way
+// the game does when a level is picked or restarted.
+pub fn start_level(app: &mut App, level: Level) {
+    let handle = app.world_mut().resource_mut::<Assets<Level>>().add(level);
+
+    let mut levels = app.world_mut().resource_mut::<Levels>();
+    levels.paths = vec![String::from("simulation")];
+    levels.handles = vec![handle];
+
+    restart_level(app);
+}
+
+pub fn restart_level(app: &mut App) {
+    app.world_mut().send_event(StartLevel(0));
+
+    // The level spawns during the first update, and the physics sets up the
+    // new bodies during the next.
+    app.update();
+    app.update();
+}
+
+pub fn step(app: &mut App, steps: usize) {
+    for _ in 0..steps {
+        app.update();
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub struct BodyState {
+    pub position: Vec2,
+    pub rotation: f32,
+    pub linear_velocity: Vec2,
+    pub angular_velocity: f32,
+}
+
+// Everything needed to play a throw out again from the moment of release.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct ThrowState {
+    pub bottle: BodyState,
+    pub contents: [BodyState; 2],
+    // Only bottles with a loose cap have one to restore.
+    #[serde(default)]
+    pub cap: Option<BodyState>,
+}
+
+impl ThrowState {
+    // These bodies moved to `position` and let go of as one rigid piece.
+    pub fn launched(&self, position: Vec2, linear_velocity: Vec2, angular_velocity: f32) -> Self {
+        let body_state = |body_state: &BodyState| {
+            let offset = body_state.position - self.bottle.position;
+            BodyState {
+                position: position + offset,
+                rotation: body_state.rotation,
+                linear_velocity: linear_velocity + angular_velocity * offset.perp(),
+                angular_velocity,
+            }
+        };
+
+        Self {
+            bottle: body_state(&self.bottle),
+            contents: [body_state(&self.contents[0]), body_state(&self.contents[1])],
+            cap: self.cap.as_ref().map(body_state),
+        }
+    }
+
+    // Whether a person could have let go of the bottle like this, having picked
+    // it up from where it rested, with the contents and cap still inside it.
+    pub fn plausible_release_from(&self, resting: &ThrowState) -> bool {
+        let attached = self
+            .contents
+            .iter()
+            .chain(&self.cap)
+            .all(|body| body.position.distance(self.bottle.position) <= BOTTLE_BODY_SIZE.y);
+
+        self.bottle.position.distance(resting.bottle.position) <= RELEASE_REACH
+            && self.bottle.linear_velocity.length() <= PLAUSIBLE_LINEAR_SPEED
+            && self.bottle.angular_velocity.abs() <= PLAUSIBLE_ANGULAR_SPEED
+            && attached
+    }
+}
+
+type BodyStateQuery<'a> = (
+    &'a mut Position,
+    &'a mut Rotation,
+    &'a mut LinearVelocity,
+    &'a mut AngularVelocity,
+    &'a mut Transform,
+);
+
+pub fn body_state(
+    position: &Position,
+    rotation: &Rotation,
+    linear_velocity: &LinearVelocity,
+    angular_velocity: &AngularVelocity,
+) -> BodyState {
+    BodyState {
+        position: position.0,
+        rotation: rotation.as_radians(),
+        linear_velocity: linear_velocity.0,
+        angular_velocity: angular_velocity.0,
+    }
+}
+
+pub fn read_throw_state(world: &mut World) -> Option<ThrowState> {
+    let mut bottle_query = world
+        .query_filtered::<(&Position, &Rotation, &LinearVelocity, &AngularVelocity), With<Bottle>>(
+        );
+    let (position, rotation, linear_velocity, angular_velocity) =
+        bottle_query.get_single(world).ok()?;
+
+    let mut throw_state = ThrowState {
+        bottle: body_state(position, rotation, linear_velocity, angular_velocity),
+        ..default()
+    };
+
+    let mut content_query = world.query::<(
+        &BottleContent,
+        &Position,
+        &Rotation,
+        &LinearVelocity,
+        &AngularVelocity,
+    )>();
+    for (content, position, rotation, linear_velocity, angular_velocity) in
+        content_query.iter(world)
+    {
+        if let Some(content_state) = throw_state.contents.get_mut(content.index) {
+            *content_state = body_state(position, rotation, linear_velocity, angular_velocity);
+        }
+    }
+
+    let mut cap_query = world
+        .query_filtered::<(&Position, &Rotation, &LinearVelocity, &AngularVelocity), With<LooseCap>>(
+        );
+    throw_state.cap = cap_query.get_single(world).ok().map(
+        |(position, rotation, linear_velocity, angular_velocity)| {
+            body_state(position, rotation, linear_velocity, angular_velocity)
+        },
+    );
+
+    Some(throw_state)
+}
+
+fn write_body_state(
+    (mut position, mut rotation, mut linear_velocity, mut angular_velocity, mut transform): bevy::ecs::query::QueryItem<BodyStateQuery>,
+    body_state: &BodyState,
+) {
+    position.0 = body_state.position;
+    *rotation = Rotation::radians(body_state.rotation);
+    linear_velocity.0 = body_state.linear_velocity;
+    angular_velocity.0 = body_state.angular_velocity;
+
+    // Keep the transform in step so it isn't synced back over the position.
+    transform.translation = body_state.position.extend(transform.translation.z);
+    transform.rotation = Quat::from_rotation_z(body_state.rotation);
+}
+
+pub fn write_throw_state(world: &mut World, throw_state: &ThrowState) {
+    let mut bottle_query = world.query_filtered::<BodyStateQuery, With<Bottle>>();
+    if let Ok(bottle) = bottle_query.get_single_mut(world) {
+        write_body_state(bottle, &throw_state.bottle);
+    }
+
+    let mut content_query = world.query::<(&BottleContent, BodyStateQuery)>();
+    for (content, body) in content_query.iter_mut(world) {
+        if let Some(content_state) = throw_state.contents.get(content.index) {
+            write_body_state(body, content_state);
+        }
+    }
+
+    let mut cap_query = world.query_filtered::<BodyStateQuery, With<LooseCap>>();
+    if let (Ok(cap), Some(cap_state)) = (cap_query.get_single_mut(world), &throw_state.cap) {
+        write_body_state(cap, cap_state);
+    }
+}
+
+// Starts the level, restores the throw and steps it, returning the bottle's
+// final state.
+pub fn simulate_throw(
+    app: &mut App,
+    level: Level,
+    throw_state: &ThrowState,
+    steps: usize,
+) -> Option<BodyState> {
+    start_level(app, level);
+    write_throw_state(app.world_mut(), throw_state);
+    step(app, steps);
+
+    read_throw_state(app.world_mut()).map(|throw_state| throw_state.bottle)
+}
+
+// How a released throw came to rest.
+pub struct Landing {
+    pub upright: bool,
+    pub standing: bool,
+    pub spilled: bool,
+    pub tricks: Option<TricksLanded>,
+    pub target_hit: Option<TargetHit>,
+}
+
+// Releases the bottle as recorded and steps until it comes to rest. None if the
+// throw was implausible or never settled.
+pub fn land_throw(app: &mut App, throw_state: &ThrowState) -> Option<Landing> {
+    write_throw_state(app.world_mut(), throw_state);
+    app.world_mut().send_event(BottleThrown);
+
+    for _ in 0..MAX_THROW_STEPS {
+        app.update();
+
+        let world = app.world_mut();
+        if world
+            .resource_mut::<Events<ImplausibleThrow>>()
+            .drain()
+            .count()
+            > 0
+        {
+            return None;
+        }
+
+        // A shattered bottle never comes to rest, and counts as falling over.
+        if world
+            .resource_mut::<Events<BottleShattered>>()
+            .drain()
+            .count()
+            > 0
+        {
+            return Some(Landing {
+                upright: false,
+                standing: false,
+                spilled: false,
+                tricks: None,
+                target_hit: None,
+            });
+        }
+
+        if let Some(landed) = world.resource_mut::<Events<BottleLanded>>().drain().last() {
+            return Some(Landing {
+                upright: landed.upright,
+                standing: landed.standing(),
+                spilled: landed.spilled,
+                tricks: world.resource_mut::<Events<TricksLanded>>().drain().last(),
+                target_hit: world.resource_mut::<Events<TargetHit>>().drain().last(),
+            });
+        }
+    }
+
+    None
+}
+
+// Like `land_throw`, returning whether the bottle landed upright.
+pub fn throw_and_land(app: &mut App, throw_state: &ThrowState) -> Option<bool> {
+    land_throw(app, throw_state).map(|landing| landing.upright)
+}
+
+// Plays a round's throws one after another on a freshly started level and
+// scores them like the game does, tricks and targets included. Each throw has
+// to be let go of near where the last one came to rest, or the spawn for the
+// first. A round ends with the first throw that doesn't land standing, so only
+// the last one may fall over. None if any throw is implausible, or falls over
+// before the last.
+pub fn replay_round(app: &mut App, level: Level, throws: &[ThrowState]) -> Option<u32> {
+    start_level(app, level);
+    let mut resting = read_throw_state(app.world_mut())?;
+
+    let mut round = Round::default();
+    for (index, throw_state) in throws.iter().enumerate() {
+        if !throw_state.plausible_release_from(&resting) {
+            return None;
+        }
+
+        let landing = land_throw(app, throw_state)?;
+        if !landing.standing {
+            // Scores nothing, and a shattered bottle has nothing left to read.
+            if index + 1 < throws.len() {
+                return None;
+            }
+            break;
+        }
+
+        round.add_landing();
+        if let Some(tricks) = &landing.tricks {
+            round.add_tricks(tricks);
+        }
+        if let Some(target_hit) = &landing.target_hit {
+            round.add_target_hit(target_hit);
+        }
+
+        resting = read_throw_state(app.world_mut())?;
+    }
+
+    Some(round.score)
+}
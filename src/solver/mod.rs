@@ -0,0 +1,41 @@
+pub mod events;
+pub mod resources;
+pub mod search;
+#[cfg(not(target_arch = "wasm32"))]
+mod systems;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::game::GameState;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::solver::events::RequestHint;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::solver::resources::{Hint, HintTask};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::solver::systems::{clear_hint, draw_hint, poll_hint, request_hint};
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::prelude::*;
+
+// Solving takes seconds, which web builds would have to spend on the main
+// thread, so hints are only offered natively.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct HintsPlugin;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Plugin for HintsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(HintTask::default());
+        app.insert_resource(Hint::default());
+        app.add_event::<RequestHint>();
+
+        app.add_systems(
+            Update,
+            (
+                request_hint,
+                poll_hint,
+                clear_hint,
+                draw_hint.run_if(in_state(GameState::Playing)),
+            )
+                .chain(),
+        );
+    }
+}
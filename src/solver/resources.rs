@@ -0,0 +1,11 @@
+use crate::solver::search::Release;
+use bevy::prelude::*;
+use bevy::tasks::Task;
+
+#[derive(Resource, Default)]
+pub struct HintTask(pub Option<Task<Option<Release>>>);
+
+// A throw that beats the current level from its start, shown until the bottle
+// is thrown.
+#[derive(Resource, Default)]
+pub struct Hint(pub Option<Release>);
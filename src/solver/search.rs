@@ -0,0 +1,296 @@
+// Searches for throws that beat a level by trying them out in the headless
+// simulation: random search to find promising throws, then the cross-entropy
+// method, a simpler relative of CMA-ES, to narrow in on them.
+
+use crate::bottle::resources::ThrowMode;
+use crate::bottle::systems::{
+    flick_spin, slingshot_spin, BOTTLE_BODY_SIZE, FLICK_MAX_SPEED, SLINGSHOT_MAX_SPEED,
+};
+use crate::levels::assets::{Level, PlatformSpec};
+use crate::physics::LENGTH_UNIT;
+use crate::random::Rng;
+use crate::simulation::{
+    headless_app, read_throw_state, restart_level, start_level, throw_and_land, BodyState,
+    ThrowState,
+};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+// Release position, linear velocity and angular velocity. Slingshots and
+// flicks spin the bottle through the launch itself, so for flicks the last one
+// is where along the bottle it's grabbed instead, and slingshots don't use it.
+const DIMENSIONS: usize = 5;
+type Parameters = [f32; DIMENSIONS];
+
+// How far from where it rests the bottle can be carried before letting go.
+const RELEASE_REACH: Vec2 = Vec2::new(120., 200.);
+// Within what a person could throw, see `PLAUSIBLE_LINEAR_SPEED` and
+// `PLAUSIBLE_ANGULAR_SPEED`.
+const LINEAR_VELOCITY_X: (f32, f32) = (-1400., 1400.);
+const LINEAR_VELOCITY_Y: (f32, f32) = (200., 1800.);
+const ANGULAR_VELOCITY: (f32, f32) = (-25., 25.);
+// How far the bottle's base may be off a platform's top and still be on it.
+const ON_PLATFORM_MARGIN: f32 = 10.;
+// Added to the cost of throws that never settle.
+const UNSETTLED_COST: f32 = 2.;
+// Keeps the search from collapsing onto a single throw too early, as a
+// fraction of each parameter's range.
+const MIN_DEVIATION: f32 = 0.02;
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub struct Release {
+    pub position: Vec2,
+    pub linear_velocity: Vec2,
+    pub angular_velocity: f32,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Target {
+    Anywhere,
+    // Anywhere when the level has no platforms.
+    AnyPlatform,
+    // Index into the level's platforms.
+    Platform(usize),
+}
+
+#[derive(Clone, Debug)]
+pub struct SolverConfig {
+    pub seed: u64,
+    pub random_samples: usize,
+    pub generations: usize,
+    pub population: usize,
+    // Best throws the next generation is sampled around.
+    pub elite: usize,
+    pub max_solutions: usize,
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            random_samples: 64,
+            generations: 12,
+            population: 16,
+            elite: 8,
+            max_solutions: 1,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Solution {
+    pub release: Release,
+    pub throw_state: ThrowState,
+}
+
+fn release(parameters: &Parameters, throw_mode: ThrowMode, resting: &BodyState) -> Release {
+    let position = Vec2::new(parameters[0], parameters[1]);
+    let linear_velocity = Vec2::new(parameters[2], parameters[3]);
+
+    let (linear_velocity, angular_velocity) = match throw_mode {
+        ThrowMode::DirectGrab => (linear_velocity, parameters[4]),
+        ThrowMode::Slingshot => {
+            let linear_velocity = linear_velocity.clamp_length_max(SLINGSHOT_MAX_SPEED);
+            (linear_velocity, slingshot_spin(linear_velocity))
+        }
+        ThrowMode::Flick => {
+            let linear_velocity = linear_velocity.clamp_length_max(FLICK_MAX_SPEED);
+            let grabbed_at = Vec2::from_angle(resting.rotation).rotate(Vec2::Y * parameters[4]);
+            (linear_velocity, flick_spin(grabbed_at, linear_velocity))
+        }
+    };
+
+    Release {
+        position,
+        linear_velocity,
+        angular_velocity,
+    }
+}
+
+fn bounds(level: &Level, resting: &ThrowState) -> [(f32, f32); DIMENSIONS] {
+    let position = resting.bottle.position;
+    let (x, y, spin) = match level.throw_mode {
+        ThrowMode::DirectGrab => (
+            (position.x - RELEASE_REACH.x, position.x + RELEASE_REACH.x),
+            (position.y, position.y + RELEASE_REACH.y),
+            ANGULAR_VELOCITY,
+        ),
+        // These launch the bottle from where it rests.
+        ThrowMode::Slingshot => ((position.x, position.x), (position.y, position.y), (0., 0.)),
+        ThrowMode::Flick => (
+            (position.x, position.x),
+            (position.y, position.y),
+            (-BOTTLE_BODY_SIZE.y / 2., BOTTLE_BODY_SIZE.y / 2.),
+        ),
+    };
+
+    [x, y, LINEAR_VELOCITY_X, LINEAR_VELOCITY_Y, spin]
+}
+
+fn targets(level: &Level, target: Target) -> Vec<&PlatformSpec> {
+    match target {
+        Target::Anywhere => Vec::new(),
+        Target::AnyPlatform => level.platforms.iter().collect(),
+        Target::Platform(index) => level.platforms.get(index).into_iter().collect(),
+    }
+}
+
+// Distance from the bottle's base to the platform's top, zero when on it.
+fn miss_distance(platform: &PlatformSpec, bottle: &BodyState) -> f32 {
+    let size = platform.size();
+    let top = platform.position + Vec2::Y * size.y / 2.;
+    let base = bottle.position - Vec2::Y * BOTTLE_BODY_SIZE.y / 2.;
+
+    if (base.x - top.x).abs() <= size.x / 2. + ON_PLATFORM_MARGIN
+        && base.y >= top.y - ON_PLATFORM_MARGIN
+    {
+        return 0.;
+    }
+
+    base.distance(top)
+}
+
+// Zero for a throw that lands upright on target, otherwise how far off it was.
+fn cost(platforms: &[&PlatformSpec], landed: Option<bool>, bottle: &BodyState) -> f32 {
+    let miss = platforms
+        .iter()
+        .map(|platform| miss_distance(platform, bottle))
+        .min_by(f32::total_cmp)
+        .unwrap_or_default();
+
+    if landed == Some(true) && miss == 0. {
+        return 0.;
+    }
+
+    let unsettled = if landed.is_none() { UNSETTLED_COST } else { 0. };
+    (bottle.rotation.abs() + miss / LENGTH_UNIT + unsettled).max(f32::EPSILON)
+}
+
+struct Search<'a> {
+    app: App,
+    throw_mode: ThrowMode,
+    resting: ThrowState,
+    platforms: Vec<&'a PlatformSpec>,
+    // Costs of the throws tried so far, lowest first once sorted.
+    samples: Vec<(f32, Parameters)>,
+    solutions: Vec<Solution>,
+    max_solutions: usize,
+}
+
+impl Search<'_> {
+    // Tries a throw, returning whether enough solutions have been found.
+    fn try_parameters(&mut self, parameters: Parameters) -> bool {
+        let release = release(&parameters, self.throw_mode, &self.resting.bottle);
+        let throw_state = self.resting.launched(
+            release.position,
+            release.linear_velocity,
+            release.angular_velocity,
+        );
+
+        restart_level(&mut self.app);
+        let landed = throw_and_land(&mut self.app, &throw_state);
+        let throw_cost = read_throw_state(self.app.world_mut()).map_or(f32::INFINITY, |landing| {
+            cost(&self.platforms, landed, &landing.bottle)
+        });
+
+        self.samples.push((throw_cost, parameters));
+        if throw_cost == 0. {
+            self.solutions.push(Solution {
+                release,
+                throw_state,
+            });
+        }
+
+        self.solutions.len() >= self.max_solutions
+    }
+}
+
+// Mean and standard deviation of each parameter over the given throws.
+fn fit(
+    samples: &[(f32, Parameters)],
+    bounds: &[(f32, f32); DIMENSIONS],
+) -> (Parameters, Parameters) {
+    let count = samples.len().max(1) as f32;
+    let mean: Parameters =
+        std::array::from_fn(|i| samples.iter().map(|(_, p)| p[i]).sum::<f32>() / count);
+    let deviation: Parameters = std::array::from_fn(|i| {
+        let variance = samples
+            .iter()
+            .map(|(_, p)| (p[i] - mean[i]).powi(2))
+            .sum::<f32>()
+            / count;
+        variance
+            .sqrt()
+            .max(MIN_DEVIATION * (bounds[i].1 - bounds[i].0))
+    });
+
+    (mean, deviation)
+}
+
+pub fn solve(level: &Level, target: Target, config: &SolverConfig) -> Vec<Solution> {
+    let mut app = headless_app();
+    start_level(&mut app, level.clone());
+    let Some(resting) = read_throw_state(app.world_mut()) else {
+        return Vec::new();
+    };
+
+    search(app, level, resting, target, config)
+}
+
+// Like `solve`, for a bottle resting somewhere else than the level's spawn,
+// like where the last throw landed.
+pub fn solve_from(
+    level: &Level,
+    resting: ThrowState,
+    target: Target,
+    config: &SolverConfig,
+) -> Vec<Solution> {
+    let mut app = headless_app();
+    start_level(&mut app, level.clone());
+
+    search(app, level, resting, target, config)
+}
+
+fn search(
+    app: App,
+    level: &Level,
+    resting: ThrowState,
+    target: Target,
+    config: &SolverConfig,
+) -> Vec<Solution> {
+    let bounds = bounds(level, &resting);
+    let mut rng = Rng::new(config.seed);
+    let mut search = Search {
+        app,
+        throw_mode: level.throw_mode,
+        resting,
+        platforms: targets(level, target),
+        samples: Vec::new(),
+        solutions: Vec::new(),
+        max_solutions: config.max_solutions,
+    };
+
+    for _ in 0..config.random_samples {
+        if search.try_parameters(bounds.map(|(min, max)| rng.range(min, max))) {
+            return search.solutions;
+        }
+    }
+
+    for _ in 0..config.generations {
+        search.samples.sort_by(|a, b| a.0.total_cmp(&b.0));
+        search.samples.truncate(config.elite);
+        let (mean, deviation) = fit(&search.samples, &bounds);
+
+        for _ in 0..config.population {
+            let parameters = std::array::from_fn(|i| {
+                (mean[i] + deviation[i] * rng.normal()).clamp(bounds[i].0, bounds[i].1)
+            });
+
+            if search.try_parameters(parameters) {
+                return search.solutions;
+            }
+        }
+    }
+
+    search.solutions
+}
@@ -0,0 +1,106 @@
+use crate::bottle::events::BottleThrown;
+use crate::levels::assets::Level;
+use crate::levels::events::StartLevel;
+use crate::levels::resources::{CurrentLevel, Levels};
+use crate::settings::resources::Settings;
+use crate::simulation::read_throw_state;
+use crate::solver::events::RequestHint;
+use crate::solver::resources::{Hint, HintTask};
+use crate::solver::search::{solve_from, SolverConfig, Target};
+use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool};
+
+const HINT_RADIUS: f32 = 12.;
+// Seconds of flight the velocity arrow covers.
+const HINT_ARROW_DURATION: f32 = 0.1;
+// Seconds of turning the spin arc covers.
+const HINT_SPIN_DURATION: f32 = 0.2;
+
+// Exclusive, to read the bottle's state the same way the simulation does.
+pub fn request_hint(world: &mut World) {
+    let requested = world.resource_mut::<Events<RequestHint>>().drain().count() > 0;
+    if !requested || world.resource::<HintTask>().0.is_some() {
+        return;
+    }
+
+    let current_level = world.resource::<CurrentLevel>().0;
+    let level_assets = world.resource::<Assets<Level>>();
+    let Some(level) = world
+        .resource::<Levels>()
+        .get(current_level, level_assets)
+        .cloned()
+    else {
+        return;
+    };
+
+    // From wherever the bottle is now, which is only the spawn before the
+    // first throw.
+    let Some(resting) = read_throw_state(world) else {
+        return;
+    };
+
+    world.resource_mut::<HintTask>().0 = Some(AsyncComputeTaskPool::get().spawn(async move {
+        solve_from(
+            &level,
+            resting,
+            Target::AnyPlatform,
+            &SolverConfig::default(),
+        )
+        .first()
+        .map(|solution| solution.release)
+    }));
+}
+
+pub fn poll_hint(mut hint_task: ResMut<HintTask>, mut hint: ResMut<Hint>) {
+    let Some(task) = &mut hint_task.0 else {
+        return;
+    };
+
+    let Some(release) = block_on(future::poll_once(task)) else {
+        return;
+    };
+
+    hint_task.0 = None;
+    if release.is_none() {
+        info!("The solver found no throw to hint at");
+    }
+    hint.0 = release;
+}
+
+pub fn clear_hint(
+    mut hint: ResMut<Hint>,
+    mut hint_task: ResMut<HintTask>,
+    mut thrown_event_reader: EventReader<BottleThrown>,
+    mut start_level_event_reader: EventReader<StartLevel>,
+) {
+    if start_level_event_reader.read().count() > 0 {
+        // Dropping the task cancels it.
+        hint_task.0 = None;
+        hint.0 = None;
+    }
+
+    if thrown_event_reader.read().count() > 0 {
+        hint.0 = None;
+    }
+}
+
+pub fn draw_hint(settings: Res<Settings>, hint: Res<Hint>, mut gizmos: Gizmos) {
+    let Some(release) = hint.0 else {
+        return;
+    };
+
+    let color = settings.palette.hint();
+    gizmos.circle_2d(release.position, HINT_RADIUS, color);
+    gizmos.arrow_2d(
+        release.position,
+        release.position + release.linear_velocity * HINT_ARROW_DURATION,
+        color,
+    );
+    gizmos.arc_2d(
+        release.position,
+        0.,
+        release.angular_velocity * HINT_SPIN_DURATION,
+        HINT_RADIUS * 2.,
+        color,
+    );
+}
@@ -0,0 +1,9 @@
+use crate::sound::resources::Playlist;
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct MusicTrack {
+    pub playlist: Playlist,
+    pub gain: f32,
+    pub fading_out: bool,
+}
@@ -0,0 +1,39 @@
+pub mod components;
+pub mod resources;
+mod systems;
+
+use crate::game::GameState;
+use crate::sound::resources::{AudioUnlocked, MusicPlayer, SloshCooldown};
+use crate::sound::systems::{
+    audio_locked, fade_music, load_sound_effects, play_break_sounds, play_impact_sounds,
+    play_landing_jingle, play_music, play_slosh_sounds, select_playlist, unlock_audio,
+};
+use bevy::prelude::*;
+
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(AudioUnlocked::default());
+        app.insert_resource(SloshCooldown::default());
+        app.insert_resource(MusicPlayer::default());
+
+        app.add_systems(PreStartup, load_sound_effects);
+        app.add_systems(
+            Update,
+            (
+                unlock_audio.run_if(audio_locked),
+                play_impact_sounds,
+                play_break_sounds,
+                play_slosh_sounds,
+                play_landing_jingle,
+                (
+                    select_playlist.run_if(state_changed::<GameState>),
+                    play_music.run_if(not(audio_locked)),
+                    fade_music,
+                )
+                    .chain(),
+            ),
+        );
+    }
+}
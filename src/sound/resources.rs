@@ -0,0 +1,70 @@
+use crate::platforms::components::Surface;
+use bevy::prelude::*;
+
+#[derive(Resource)]
+pub struct SoundEffects {
+    pub impact_wood: Handle<AudioSource>,
+    pub impact_cardboard: Handle<AudioSource>,
+    pub impact_metal: Handle<AudioSource>,
+    pub slosh: Handle<AudioSource>,
+    pub landing_jingle: Handle<AudioSource>,
+}
+
+impl SoundEffects {
+    pub fn impact(&self, surface: Surface) -> Handle<AudioSource> {
+        match surface {
+            Surface::Wood => self.impact_wood.clone(),
+            Surface::Cardboard => self.impact_cardboard.clone(),
+            Surface::Metal | Surface::Ceramic => self.impact_metal.clone(),
+        }
+    }
+}
+
+// Browsers refuse to start audio before a user gesture. The web build resumes
+// the audio context from `restart-audio-context.js`, so sounds triggered before
+// the first input would be lost anyway and are skipped instead.
+#[derive(Resource)]
+pub struct AudioUnlocked(pub bool);
+
+impl Default for AudioUnlocked {
+    fn default() -> Self {
+        Self(cfg!(not(target_arch = "wasm32")))
+    }
+}
+
+#[derive(Resource)]
+pub struct SloshCooldown(pub Timer);
+
+impl Default for SloshCooldown {
+    fn default() -> Self {
+        Self(Timer::from_seconds(0.35, TimerMode::Once))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Playlist {
+    #[default]
+    Menu,
+    Gameplay,
+}
+
+#[derive(Resource)]
+pub struct MusicPlaylists {
+    pub menu: Vec<Handle<AudioSource>>,
+    pub gameplay: Vec<Handle<AudioSource>>,
+}
+
+impl MusicPlaylists {
+    pub fn tracks(&self, playlist: Playlist) -> &[Handle<AudioSource>] {
+        match playlist {
+            Playlist::Menu => &self.menu,
+            Playlist::Gameplay => &self.gameplay,
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct MusicPlayer {
+    pub playlist: Playlist,
+    pub track_index: usize,
+}
@@ -0,0 +1,288 @@
+use crate::bottle::components::{Bottle, BottleContent, Spilled};
+use crate::bottle::events::BottleLanded;
+use crate::breakables::components::BreakMaterial;
+use crate::breakables::events::PropDamaged;
+use crate::game::GameState;
+use crate::physics::events::Impact;
+use crate::platforms::components::Surface;
+use crate::settings::resources::Settings;
+use crate::sound::components::MusicTrack;
+use crate::sound::resources::{
+    AudioUnlocked, MusicPlayer, MusicPlaylists, Playlist, SloshCooldown, SoundEffects,
+};
+use avian2d::prelude::*;
+use bevy::audio::{AudioSinkPlayback, Volume};
+use bevy::prelude::*;
+
+const MIN_IMPACT_SPEED: f32 = 60.;
+const MAX_IMPACT_SPEED: f32 = 1600.;
+const MIN_SLOSH_SPEED: f32 = 150.;
+const MAX_SLOSH_SPEED: f32 = 900.;
+const CROSSFADE_DURATION: f32 = 1.5;
+
+pub fn load_sound_effects(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(SoundEffects {
+        impact_wood: asset_server.load("audio/impact_wood.wav"),
+        impact_cardboard: asset_server.load("audio/impact_cardboard.wav"),
+        impact_metal: asset_server.load("audio/impact_metal.wav"),
+        slosh: asset_server.load("audio/slosh.wav"),
+        landing_jingle: asset_server.load("audio/landing_jingle.wav"),
+    });
+
+    commands.insert_resource(MusicPlaylists {
+        menu: vec![asset_server.load("audio/music_menu.wav")],
+        gameplay: vec![
+            asset_server.load("audio/music_gameplay_1.wav"),
+            asset_server.load("audio/music_gameplay_2.wav"),
+        ],
+    });
+}
+
+pub fn audio_locked(audio_unlocked: Res<AudioUnlocked>) -> bool {
+    !audio_unlocked.0
+}
+
+pub fn unlock_audio(
+    mut audio_unlocked: ResMut<AudioUnlocked>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    touches: Res<Touches>,
+) {
+    if buttons.get_just_pressed().next().is_some()
+        || keys.get_just_pressed().next().is_some()
+        || touches.any_just_pressed()
+    {
+        audio_unlocked.0 = true;
+    }
+}
+
+fn normalized(value: f32, min: f32, max: f32) -> f32 {
+    ((value - min) / (max - min)).clamp(0., 1.)
+}
+
+fn play_sound(
+    commands: &mut Commands,
+    settings: &Settings,
+    source: Handle<AudioSource>,
+    volume: f32,
+    speed: f32,
+) {
+    let volume = volume * settings.audio.sfx_gain();
+    if volume <= 0. {
+        return;
+    }
+
+    commands.spawn(AudioBundle {
+        source,
+        settings: PlaybackSettings::DESPAWN
+            .with_volume(Volume::new(volume))
+            .with_speed(speed),
+    });
+}
+
+pub fn play_impact_sounds(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    sound_effects: Res<SoundEffects>,
+    audio_unlocked: Res<AudioUnlocked>,
+    mut impact_event_reader: EventReader<Impact>,
+    bottle_query: Query<(), With<Bottle>>,
+    surface_query: Query<&Surface>,
+) {
+    for impact in impact_event_reader.read() {
+        let platform = if bottle_query.contains(impact.body_1) {
+            impact.body_2
+        } else if bottle_query.contains(impact.body_2) {
+            impact.body_1
+        } else {
+            continue;
+        };
+        let Ok(surface) = surface_query.get(platform) else {
+            continue;
+        };
+
+        if !audio_unlocked.0 || impact.speed < MIN_IMPACT_SPEED {
+            continue;
+        }
+
+        let strength = normalized(impact.speed, MIN_IMPACT_SPEED, MAX_IMPACT_SPEED);
+        play_sound(
+            &mut commands,
+            &settings,
+            sound_effects.impact(*surface),
+            0.2 + 0.8 * strength,
+            1.1 - 0.2 * strength,
+        );
+    }
+}
+
+pub fn play_break_sounds(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    sound_effects: Res<SoundEffects>,
+    audio_unlocked: Res<AudioUnlocked>,
+    mut damaged_event_reader: EventReader<PropDamaged>,
+) {
+    for damaged in damaged_event_reader.read() {
+        if !audio_unlocked.0 {
+            continue;
+        }
+
+        let volume = if damaged.broken { 1. } else { 0.6 };
+        // Slowed down cardboard sounds like tearing, sped up metal like breaking glass.
+        let (source, speed) = match damaged.material {
+            BreakMaterial::Cardboard => (sound_effects.impact_cardboard.clone(), 0.7),
+            BreakMaterial::Glass | BreakMaterial::Ceramic => {
+                (sound_effects.impact_metal.clone(), 1.8)
+            }
+        };
+
+        play_sound(&mut commands, &settings, source, volume, speed);
+    }
+}
+
+pub fn play_slosh_sounds(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    sound_effects: Res<SoundEffects>,
+    audio_unlocked: Res<AudioUnlocked>,
+    mut slosh_cooldown: ResMut<SloshCooldown>,
+    bottle_query: Query<&LinearVelocity, With<Bottle>>,
+    content_query: Query<&LinearVelocity, (With<BottleContent>, Without<Spilled>)>,
+) {
+    slosh_cooldown.0.tick(time.delta());
+
+    let Ok(bottle_velocity) = bottle_query.get_single() else {
+        return;
+    };
+
+    let relative_speed = content_query
+        .iter()
+        .map(|content_velocity| (content_velocity.0 - bottle_velocity.0).length())
+        .fold(0., f32::max);
+
+    if !audio_unlocked.0 || relative_speed < MIN_SLOSH_SPEED || !slosh_cooldown.0.finished() {
+        return;
+    }
+
+    slosh_cooldown.0.reset();
+    play_sound(
+        &mut commands,
+        &settings,
+        sound_effects.slosh.clone(),
+        normalized(relative_speed, MIN_SLOSH_SPEED, MAX_SLOSH_SPEED),
+        1.,
+    );
+}
+
+pub fn play_landing_jingle(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    sound_effects: Res<SoundEffects>,
+    audio_unlocked: Res<AudioUnlocked>,
+    mut landed_event_reader: EventReader<BottleLanded>,
+) {
+    for landed in landed_event_reader.read() {
+        if audio_unlocked.0 && landed.standing() {
+            play_sound(
+                &mut commands,
+                &settings,
+                sound_effects.landing_jingle.clone(),
+                0.8,
+                1.,
+            );
+        }
+    }
+}
+
+pub fn select_playlist(state: Res<State<GameState>>, mut music_player: ResMut<MusicPlayer>) {
+    let playlist = match state.get() {
+        GameState::Loading
+        | GameState::Title
+        | GameState::LevelSelect
+        | GameState::Achievements => Playlist::Menu,
+        GameState::Playing | GameState::Paused | GameState::RoundSummary | GameState::Editor => {
+            Playlist::Gameplay
+        }
+        GameState::Settings => return,
+    };
+
+    if music_player.playlist != playlist {
+        music_player.playlist = playlist;
+        music_player.track_index = 0;
+    }
+}
+
+pub fn play_music(
+    mut commands: Commands,
+    music_playlists: Res<MusicPlaylists>,
+    mut music_player: ResMut<MusicPlayer>,
+    mut track_query: Query<(Entity, &mut MusicTrack, Option<&AudioSink>)>,
+) {
+    let mut playing = false;
+
+    for (entity, mut track, sink) in &mut track_query {
+        if track.fading_out {
+            continue;
+        }
+
+        if track.playlist != music_player.playlist {
+            track.fading_out = true;
+            continue;
+        }
+
+        if sink.is_some_and(|sink| sink.empty()) {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        playing = true;
+    }
+
+    let tracks = music_playlists.tracks(music_player.playlist);
+    if playing || tracks.is_empty() {
+        return;
+    }
+
+    let track_index = music_player.track_index % tracks.len();
+    music_player.track_index = track_index + 1;
+
+    commands.spawn((
+        AudioBundle {
+            source: tracks[track_index].clone(),
+            settings: PlaybackSettings::ONCE.with_volume(Volume::new(0.)),
+        },
+        MusicTrack {
+            playlist: music_player.playlist,
+            gain: 0.,
+            fading_out: false,
+        },
+    ));
+}
+
+pub fn fade_music(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mut track_query: Query<(Entity, &mut MusicTrack, Option<&AudioSink>)>,
+) {
+    let step = time.delta_seconds() / CROSSFADE_DURATION;
+
+    for (entity, mut track, sink) in &mut track_query {
+        track.gain = if track.fading_out {
+            (track.gain - step).max(0.)
+        } else {
+            (track.gain + step).min(1.)
+        };
+
+        if track.fading_out && track.gain <= 0. {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        if let Some(sink) = sink {
+            sink.set_volume(track.gain * settings.audio.music_gain());
+        }
+    }
+}
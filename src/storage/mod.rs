@@ -0,0 +1,67 @@
+// Key-value persistence for small RON documents. Native builds keep one file
+// per key in a `saves` directory, web builds use the browser's `localStorage`.
+
+#[cfg(not(target_arch = "wasm32"))]
+const SAVE_DIRECTORY: &str = "saves";
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load(key: &str) -> Option<String> {
+    let path = std::path::Path::new(SAVE_DIRECTORY).join(format!("{key}.ron"));
+    std::fs::read_to_string(path).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save(key: &str, value: &str) {
+    let directory = std::path::Path::new(SAVE_DIRECTORY);
+    let result = std::fs::create_dir_all(directory)
+        .and_then(|_| std::fs::write(directory.join(format!("{key}.ron")), value));
+
+    if let Err(error) = result {
+        bevy::log::warn!("Failed to save {key}: {error}");
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn load(key: &str) -> Option<String> {
+    local_storage()?
+        .get_item(&format!("bottle_flip.{key}"))
+        .ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn save(key: &str, value: &str) {
+    let Some(storage) = local_storage() else {
+        bevy::log::warn!("Failed to save {key}: localStorage is unavailable");
+        return;
+    };
+
+    if storage
+        .set_item(&format!("bottle_flip.{key}"), value)
+        .is_err()
+    {
+        bevy::log::warn!("Failed to save {key}: localStorage rejected the write");
+    }
+}
+
+pub fn load_ron<T: serde::de::DeserializeOwned>(key: &str) -> Option<T> {
+    let value = load(key)?;
+    match ron::from_str(&value) {
+        Ok(value) => Some(value),
+        Err(error) => {
+            bevy::log::warn!("Ignoring unreadable {key}: {error}");
+            None
+        }
+    }
+}
+
+pub fn save_ron<T: serde::Serialize>(key: &str, value: &T) {
+    match ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()) {
+        Ok(value) => save(key, &value),
+        Err(error) => bevy::log::warn!("Failed to serialize {key}: {error}"),
+    }
+}
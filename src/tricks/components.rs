@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+// Trick names floating up from where the bottle landed.
+#[derive(Component)]
+pub struct TrickPopup {
+    pub timer: Timer,
+}
@@ -0,0 +1,15 @@
+use crate::tricks::resources::Trick;
+use bevy::prelude::*;
+
+// Sent along with a standing landing that had any tricks to it.
+#[derive(Event, Clone)]
+pub struct TricksLanded {
+    pub tricks: Vec<Trick>,
+    pub position: Vec2,
+}
+
+impl TricksLanded {
+    pub fn score(&self) -> u32 {
+        self.tricks.iter().map(|trick| trick.score()).sum()
+    }
+}
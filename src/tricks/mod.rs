@@ -0,0 +1,42 @@
+pub mod components;
+pub mod events;
+pub mod resources;
+mod systems;
+
+use crate::bottle::systems::detect_bottle_landing;
+use crate::game::{GameState, Scoring};
+use crate::tricks::events::TricksLanded;
+use crate::tricks::resources::TrickTracker;
+use crate::tricks::systems::{
+    detect_tricks, spawn_trick_popups, start_trick_tracking, track_tricks, update_trick_popups,
+};
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+// Tells flips, cap landings, bounces and the like apart from plain landings.
+pub struct TricksPlugin;
+
+impl Plugin for TricksPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(TrickTracker::default());
+        app.add_event::<TricksLanded>();
+
+        app.add_systems(
+            PhysicsSchedule,
+            track_tricks
+                .after(PhysicsStepSet::Last)
+                .run_if(in_state(GameState::Playing)),
+        );
+        app.add_systems(
+            Update,
+            (
+                (start_trick_tracking, detect_tricks)
+                    .chain()
+                    .after(detect_bottle_landing)
+                    .before(Scoring)
+                    .run_if(in_state(GameState::Playing)),
+                (spawn_trick_popups, update_trick_popups).chain(),
+            ),
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::{FRAC_PI_4, PI, TAU};
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Trick {
+    SingleFlip,
+    DoubleFlip,
+    // Three or more.
+    TripleFlip,
+    // Spinning against the direction of the throw.
+    ReverseFlip,
+    CapLanding,
+    // Came down on its side and rolled up onto its base.
+    SideRoll,
+    // Hit a box first and ended up on the floor.
+    Bounce,
+    // Landed on a box that was moving when the bottle touched it.
+    BoxSurf,
+}
+
+impl Trick {
+    pub fn key(self) -> &'static str {
+        match self {
+            Trick::SingleFlip => "trick_single_flip",
+            Trick::DoubleFlip => "trick_double_flip",
+            Trick::TripleFlip => "trick_triple_flip",
+            Trick::ReverseFlip => "trick_reverse_flip",
+            Trick::CapLanding => "trick_cap_landing",
+            Trick::SideRoll => "trick_side_roll",
+            Trick::Bounce => "trick_bounce",
+            Trick::BoxSurf => "trick_box_surf",
+        }
+    }
+
+    pub fn is_flip(self) -> bool {
+        matches!(
+            self,
+            Trick::SingleFlip | Trick::DoubleFlip | Trick::TripleFlip
+        )
+    }
+
+    pub fn score(self) -> u32 {
+        match self {
+            Trick::SingleFlip => 50,
+            Trick::DoubleFlip => 150,
+            Trick::TripleFlip => 300,
+            Trick::ReverseFlip => 100,
+            Trick::CapLanding => 500,
+            Trick::SideRoll => 150,
+            Trick::Bounce => 200,
+            Trick::BoxSurf => 250,
+        }
+    }
+}
+
+// What the bottle touched during a throw.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Touch {
+    Ground,
+    // Shelves and anything else that doesn't move.
+    Fixed,
+    // A box or trash can, and whether it was moving when first touched.
+    Dynamic { moving: bool },
+}
+
+// Follows the bottle from release until it lands, for telling its tricks.
+#[derive(Resource, Default)]
+pub struct TrickTracker {
+    // Total turn since release, counterclockwise and not wrapped.
+    pub rotation: f32,
+    pub last_angle: f32,
+    // Sign of the horizontal release velocity.
+    pub direction: f32,
+    // The bottle's angle when it first touched anything.
+    pub touchdown_angle: Option<f32>,
+    // Bodies in the order they were first touched.
+    pub touched: Vec<(Entity, Touch)>,
+    // Bodies touched during the latest physics step.
+    pub resting_on: Vec<Entity>,
+    pub cap_contact: bool,
+}
+
+fn wrap_angle(angle: f32) -> f32 {
+    (angle + PI).rem_euclid(TAU) - PI
+}
+
+impl TrickTracker {
+    pub fn start(angle: f32, horizontal_velocity: f32) -> Self {
+        Self {
+            last_angle: angle,
+            direction: horizontal_velocity.signum(),
+            ..default()
+        }
+    }
+
+    pub fn turn_to(&mut self, angle: f32) {
+        self.rotation += wrap_angle(angle - self.last_angle);
+        self.last_angle = angle;
+    }
+
+    fn resting_touches(&self) -> impl Iterator<Item = Touch> + '_ {
+        self.touched
+            .iter()
+            .filter(|(body, _)| self.resting_on.contains(body))
+            .map(|(_, touch)| *touch)
+    }
+
+    pub fn tricks(&self, upright: bool, upside_down: bool) -> Vec<Trick> {
+        let mut tricks = Vec::new();
+
+        if upright {
+            let flips = (self.rotation.abs() / TAU).round() as u32;
+            match flips {
+                0 => {}
+                1 => tricks.push(Trick::SingleFlip),
+                2 => tricks.push(Trick::DoubleFlip),
+                _ => tricks.push(Trick::TripleFlip),
+            }
+
+            // Counterclockwise is backwards when thrown to the right.
+            if flips > 0 && self.rotation * self.direction > 0. {
+                tricks.push(Trick::ReverseFlip);
+            }
+
+            if self
+                .touchdown_angle
+                .is_some_and(|angle| (wrap_angle(angle).abs() - PI / 2.).abs() < FRAC_PI_4)
+            {
+                tricks.push(Trick::SideRoll);
+            }
+        }
+
+        if upside_down && self.cap_contact {
+            tricks.push(Trick::CapLanding);
+        }
+
+        let touched_box = self
+            .touched
+            .iter()
+            .any(|(_, touch)| matches!(touch, Touch::Dynamic { .. }));
+        if touched_box
+            && !self.resting_on.is_empty()
+            && self.resting_touches().all(|touch| touch == Touch::Ground)
+        {
+            tricks.push(Trick::Bounce);
+        }
+
+        if self
+            .resting_touches()
+            .any(|touch| touch == Touch::Dynamic { moving: true })
+        {
+            tricks.push(Trick::BoxSurf);
+        }
+
+        tricks
+    }
+}
@@ -0,0 +1,185 @@
+use crate::bottle::components::{Bottle, BottleCap};
+use crate::bottle::events::{BottleLanded, BottleThrown};
+use crate::i18n::translate;
+use crate::platforms::components::{DynamicPlatform, Ground};
+use crate::settings::resources::Settings;
+use crate::tricks::components::TrickPopup;
+use crate::tricks::events::TricksLanded;
+use crate::tricks::resources::{Touch, TrickTracker};
+use crate::ui::widgets::text_style;
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+// A box slower than this counts as standing still.
+const MOVING_SPEED: f32 = 20.;
+const POPUP_DURATION: f32 = 1.5;
+const POPUP_RISE_SPEED: f32 = 60.;
+const POPUP_OFFSET: Vec2 = Vec2::new(0., 120.);
+const POPUP_FONT_SIZE: f32 = 28.;
+const POPUP_Z: f32 = 20.;
+
+pub fn start_trick_tracking(
+    mut trick_tracker: ResMut<TrickTracker>,
+    bottle_query: Query<(&Rotation, &LinearVelocity), With<Bottle>>,
+    mut thrown_event_reader: EventReader<BottleThrown>,
+) {
+    if thrown_event_reader.read().count() == 0 {
+        return;
+    }
+
+    if let Ok((rotation, linear_velocity)) = bottle_query.get_single() {
+        *trick_tracker = TrickTracker::start(rotation.as_radians(), linear_velocity.x);
+    }
+}
+
+// Runs every physics step, so fast spins and brief touches aren't missed.
+pub fn track_tricks(
+    mut trick_tracker: ResMut<TrickTracker>,
+    collisions: Res<Collisions>,
+    bottle_query: Query<(Entity, &Rotation), With<Bottle>>,
+    collider_query: Query<(Entity, &ColliderParent, Has<BottleCap>)>,
+    body_query: Query<(Has<Ground>, Has<DynamicPlatform>, Option<&LinearVelocity>)>,
+    sensor_query: Query<(), With<Sensor>>,
+) {
+    let Ok((bottle, rotation)) = bottle_query.get_single() else {
+        return;
+    };
+
+    trick_tracker.turn_to(rotation.as_radians());
+    trick_tracker.resting_on.clear();
+    trick_tracker.cap_contact = false;
+
+    for (collider, parent, is_cap) in &collider_query {
+        // A loose cap is a body of its own, but still part of the bottle.
+        if parent.get() != bottle && !is_cap {
+            continue;
+        }
+
+        for contacts in collisions.collisions_with_entity(collider) {
+            if !contacts.during_current_frame {
+                continue;
+            }
+
+            let other = if contacts.entity1 == collider {
+                contacts.entity2
+            } else {
+                contacts.entity1
+            };
+            // Target zones sit above surfaces without holding anything up.
+            if sensor_query.contains(other) {
+                continue;
+            }
+
+            let Ok((_, other_parent, _)) = collider_query.get(other) else {
+                continue;
+            };
+            let body = other_parent.get();
+            if body == bottle {
+                continue;
+            }
+
+            if trick_tracker.touchdown_angle.is_none() {
+                trick_tracker.touchdown_angle = Some(trick_tracker.last_angle);
+            }
+
+            if !trick_tracker
+                .touched
+                .iter()
+                .any(|(touched, _)| *touched == body)
+            {
+                let touch = match body_query.get(body) {
+                    Ok((true, _, _)) => Touch::Ground,
+                    Ok((_, true, linear_velocity)) => Touch::Dynamic {
+                        moving: linear_velocity
+                            .is_some_and(|velocity| velocity.length() > MOVING_SPEED),
+                    },
+                    _ => Touch::Fixed,
+                };
+                trick_tracker.touched.push((body, touch));
+            }
+
+            if !trick_tracker.resting_on.contains(&body) {
+                trick_tracker.resting_on.push(body);
+            }
+            trick_tracker.cap_contact |= is_cap;
+        }
+    }
+}
+
+pub fn detect_tricks(
+    trick_tracker: Res<TrickTracker>,
+    bottle_query: Query<&Position, With<Bottle>>,
+    mut landed_event_reader: EventReader<BottleLanded>,
+    mut tricks_event_writer: EventWriter<TricksLanded>,
+) {
+    for landed in landed_event_reader.read() {
+        if !landed.standing() {
+            continue;
+        }
+
+        let tricks = trick_tracker.tricks(landed.upright, landed.upside_down);
+        if tricks.is_empty() {
+            continue;
+        }
+
+        tricks_event_writer.send(TricksLanded {
+            tricks,
+            position: bottle_query
+                .get_single()
+                .map_or(Vec2::ZERO, |position| position.0),
+        });
+    }
+}
+
+pub fn spawn_trick_popups(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    mut tricks_event_reader: EventReader<TricksLanded>,
+) {
+    for tricks_landed in tricks_event_reader.read() {
+        let names: Vec<&str> = tricks_landed
+            .tricks
+            .iter()
+            .map(|trick| translate(settings.language, trick.key()))
+            .collect();
+
+        let mut style = text_style(POPUP_FONT_SIZE);
+        style.color = settings.palette.accent();
+
+        commands.spawn((
+            Text2dBundle {
+                text: Text::from_section(
+                    format!("{}\n+{}", names.join(" + "), tricks_landed.score()),
+                    style,
+                )
+                .with_justify(JustifyText::Center),
+                transform: Transform::from_translation(
+                    (tricks_landed.position + POPUP_OFFSET).extend(POPUP_Z),
+                ),
+                ..default()
+            },
+            TrickPopup {
+                timer: Timer::from_seconds(POPUP_DURATION, TimerMode::Once),
+            },
+        ));
+    }
+}
+
+pub fn update_trick_popups(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut popup_query: Query<(Entity, &mut TrickPopup, &mut Transform, &mut Text)>,
+) {
+    for (entity, mut popup, mut transform, mut text) in &mut popup_query {
+        if popup.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        transform.translation.y += POPUP_RISE_SPEED * time.delta_seconds();
+        let alpha = popup.timer.fraction_remaining();
+        for section in &mut text.sections {
+            section.style.color.set_alpha(alpha);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+// Physics values designers tune for the feel of a flip. Loaded from
+// `assets/physics.tuning.ron` and editable at runtime from the debug build.
+#[derive(Asset, Resource, TypePath, Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(default)]
+pub struct PhysicsTuning {
+    pub gravity: f32,
+    pub substep_count: u32,
+    pub bottle_density: f32,
+    pub content_density: f32,
+    pub bottle_angular_damping: f32,
+    pub grab_angular_damping: f32,
+    // Spring pulling the grabbed point towards the pointer, and its limit.
+    pub grab_stiffness: f32,
+    pub grab_damping: f32,
+    pub max_grab_acceleration: f32,
+    pub max_linear_speed: f32,
+    pub max_angular_speed: f32,
+    // Continuous collision for fast bodies: sweeping the bottle and platforms
+    // between steps, and how far ahead contacts are predicted, or unbounded.
+    pub swept_ccd: bool,
+    pub speculative_margin: Option<f32>,
+    // Travel of the content balls along the bottle, relative to its centre.
+    pub content_limits: (f32, f32),
+}
+
+impl Default for PhysicsTuning {
+    fn default() -> Self {
+        Self {
+            gravity: 2400.,
+            substep_count: 6,
+            bottle_density: 0.4,
+            content_density: 4.,
+            bottle_angular_damping: 0.5,
+            grab_angular_damping: 20.,
+            grab_stiffness: 600.,
+            grab_damping: 49.,
+            max_grab_acceleration: 20000.,
+            max_linear_speed: 3000.,
+            max_angular_speed: 40.,
+            swept_ccd: true,
+            speculative_margin: None,
+            content_limits: (-27., 57.),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+pub mod assets;
+mod resources;
+pub mod systems;
+
+use crate::ron_asset::RonAssetPlugin;
+use crate::tuning::assets::PhysicsTuning;
+use crate::tuning::systems::{
+    apply_continuous_collision, apply_physics_tuning, load_physics_tuning, update_physics_tuning,
+};
+use bevy::prelude::*;
+
+pub struct TuningPlugin {
+    // Headless simulations keep the defaults so results don't depend on when,
+    // or whether, the config asset finishes loading.
+    pub load_config: bool,
+}
+
+impl Default for TuningPlugin {
+    fn default() -> Self {
+        Self { load_config: true }
+    }
+}
+
+impl Plugin for TuningPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RonAssetPlugin::<PhysicsTuning>::new(&["tuning.ron"]));
+        app.insert_resource(PhysicsTuning::default());
+
+        if self.load_config {
+            app.add_systems(Startup, load_physics_tuning);
+            app.add_systems(Update, update_physics_tuning.before(apply_physics_tuning));
+        }
+
+        app.add_systems(
+            Update,
+            (
+                apply_physics_tuning.run_if(resource_changed::<PhysicsTuning>),
+                apply_continuous_collision,
+            )
+                .chain(),
+        );
+    }
+}
@@ -0,0 +1,5 @@
+use crate::tuning::assets::PhysicsTuning;
+use bevy::prelude::*;
+
+#[derive(Resource)]
+pub struct PhysicsTuningHandle(pub Handle<PhysicsTuning>);
@@ -0,0 +1,113 @@
+use crate::bottle::components::{
+    Bottle, BottleContent, BottleContentJoint, EmptyContent, Grabbable,
+};
+use crate::bottle::systems::EMPTY_CONTENT_DENSITY;
+use crate::platforms::components::DynamicPlatform;
+use crate::tuning::assets::PhysicsTuning;
+use crate::tuning::resources::PhysicsTuningHandle;
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+pub const TUNING_PATH: &str = "physics.tuning.ron";
+
+pub fn load_physics_tuning(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(PhysicsTuningHandle(asset_server.load(TUNING_PATH)));
+}
+
+pub fn update_physics_tuning(
+    mut commands: Commands,
+    physics_tuning_handle: Res<PhysicsTuningHandle>,
+    physics_tunings: Res<Assets<PhysicsTuning>>,
+    mut asset_event_reader: EventReader<AssetEvent<PhysicsTuning>>,
+) {
+    for event in asset_event_reader.read() {
+        if !event.is_loaded_with_dependencies(&physics_tuning_handle.0)
+            && !event.is_modified(&physics_tuning_handle.0)
+        {
+            continue;
+        }
+
+        if let Some(physics_tuning) = physics_tunings.get(&physics_tuning_handle.0) {
+            commands.insert_resource(physics_tuning.clone());
+        }
+    }
+}
+
+pub fn apply_physics_tuning(
+    physics_tuning: Res<PhysicsTuning>,
+    mut gravity: ResMut<Gravity>,
+    mut substep_count: ResMut<SubstepCount>,
+    mut bottle_query: Query<&mut AngularDamping, With<Bottle>>,
+    mut density_query: Query<
+        (&mut ColliderDensity, Has<BottleContent>, Has<EmptyContent>),
+        Or<(With<Grabbable>, With<BottleContent>)>,
+    >,
+    mut content_joint_query: Query<&mut PrismaticJoint, With<BottleContentJoint>>,
+) {
+    gravity.0 = Vec2::NEG_Y * physics_tuning.gravity;
+    substep_count.0 = physics_tuning.substep_count.max(1);
+
+    for mut angular_damping in &mut bottle_query {
+        angular_damping.0 = physics_tuning.bottle_angular_damping;
+    }
+
+    for (mut density, is_content, is_empty) in &mut density_query {
+        density.0 = if is_empty {
+            EMPTY_CONTENT_DENSITY
+        } else if is_content {
+            physics_tuning.content_density
+        } else {
+            physics_tuning.bottle_density
+        };
+    }
+
+    let (min, max) = physics_tuning.content_limits;
+    for mut joint in &mut content_joint_query {
+        joint.free_axis_limits = Some(DistanceLimit::new(min, max));
+    }
+}
+
+pub fn apply_continuous_collision(
+    mut commands: Commands,
+    physics_tuning: Res<PhysicsTuning>,
+    body_query: Query<(Entity, Ref<RigidBody>), Or<(With<Bottle>, With<DynamicPlatform>)>>,
+) {
+    for (entity, rigid_body) in &body_query {
+        if !rigid_body.is_added() && !physics_tuning.is_changed() {
+            continue;
+        }
+
+        let mut body = commands.entity(entity);
+
+        // The bottle spins fast, so its sweep has to account for rotation too.
+        if physics_tuning.swept_ccd {
+            body.insert(SweptCcd::NON_LINEAR);
+        } else {
+            body.remove::<SweptCcd>();
+        }
+
+        match physics_tuning.speculative_margin {
+            Some(margin) => body.insert(SpeculativeMargin(margin)),
+            None => body.remove::<SpeculativeMargin>(),
+        };
+    }
+}
+
+// Writes the tuned values back over the config asset so they ship with the game.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save_physics_tuning(physics_tuning: &PhysicsTuning) {
+    let path = std::path::Path::new("assets").join(TUNING_PATH);
+    let result = ron::ser::to_string_pretty(physics_tuning, ron::ser::PrettyConfig::default())
+        .map_err(|error| error.to_string())
+        .and_then(|value| std::fs::write(&path, value).map_err(|error| error.to_string()));
+
+    match result {
+        Ok(()) => info!("Saved physics tuning to {}", path.display()),
+        Err(error) => warn!("Failed to save physics tuning: {error}"),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn save_physics_tuning(_physics_tuning: &PhysicsTuning) {
+    warn!("Saving physics tuning is not supported in web builds");
+}
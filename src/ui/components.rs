@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct Slider {
+    pub value: f32,
+}
+
+#[derive(Component)]
+pub struct SliderFill;
+
+#[derive(Component)]
+pub struct Toggle {
+    pub on: bool,
+}
+
+#[derive(Component)]
+pub struct MenuButton;
+
+#[derive(Component)]
+pub struct Disabled;
@@ -0,0 +1,4 @@
+use bevy::prelude::*;
+
+#[derive(Event)]
+pub struct ButtonActivated(pub Entity);
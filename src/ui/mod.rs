@@ -0,0 +1,43 @@
+pub mod components;
+pub mod events;
+pub mod resources;
+mod systems;
+pub mod widgets;
+
+use crate::ui::events::ButtonActivated;
+use crate::ui::resources::Focus;
+use crate::ui::systems::{
+    activate_buttons, drag_sliders, draw_button_focus, draw_slider_fills, draw_toggles,
+    focus_hovered_buttons, navigate_focus, nudge_focused_slider, press_toggles,
+};
+use bevy::prelude::*;
+
+// Systems reading menu navigation input, so other input handlers can run around them.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UiInput;
+
+pub struct UiWidgetsPlugin;
+
+impl Plugin for UiWidgetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Focus::default());
+        app.add_event::<ButtonActivated>();
+
+        app.add_systems(
+            Update,
+            (
+                (
+                    focus_hovered_buttons,
+                    navigate_focus,
+                    nudge_focused_slider,
+                    activate_buttons,
+                )
+                    .chain()
+                    .in_set(UiInput),
+                (drag_sliders, press_toggles),
+                (draw_slider_fills, draw_toggles, draw_button_focus),
+            )
+                .chain(),
+        );
+    }
+}
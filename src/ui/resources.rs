@@ -0,0 +1,4 @@
+use bevy::prelude::*;
+
+#[derive(Resource, Default)]
+pub struct Focus(pub Option<Entity>);
@@ -0,0 +1,248 @@
+use crate::settings::resources::Settings;
+use crate::ui::components::{Disabled, MenuButton, Slider, SliderFill, Toggle};
+use crate::ui::events::ButtonActivated;
+use crate::ui::resources::Focus;
+use crate::ui::widgets::INACTIVE_COLOR;
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+const SLIDER_STEP: f32 = 0.1;
+
+const NAVIGATION_INPUTS: [(KeyCode, GamepadButtonType, Vec2); 4] = [
+    (KeyCode::ArrowUp, GamepadButtonType::DPadUp, Vec2::NEG_Y),
+    (KeyCode::ArrowDown, GamepadButtonType::DPadDown, Vec2::Y),
+    (KeyCode::ArrowLeft, GamepadButtonType::DPadLeft, Vec2::NEG_X),
+    (KeyCode::ArrowRight, GamepadButtonType::DPadRight, Vec2::X),
+];
+
+fn gamepad_just_pressed(
+    gamepads: &Gamepads,
+    gamepad_buttons: &ButtonInput<GamepadButton>,
+    button_type: GamepadButtonType,
+) -> bool {
+    gamepads
+        .iter()
+        .any(|gamepad| gamepad_buttons.just_pressed(GamepadButton::new(gamepad, button_type)))
+}
+
+fn navigation_direction(
+    keys: &ButtonInput<KeyCode>,
+    gamepads: &Gamepads,
+    gamepad_buttons: &ButtonInput<GamepadButton>,
+) -> Option<Vec2> {
+    NAVIGATION_INPUTS
+        .into_iter()
+        .find(|(key, button_type, _)| {
+            keys.just_pressed(*key) || gamepad_just_pressed(gamepads, gamepad_buttons, *button_type)
+        })
+        .map(|(_, _, direction)| direction)
+}
+
+pub fn drag_sliders(mut slider_query: Query<(&Interaction, &RelativeCursorPosition, &mut Slider)>) {
+    for (interaction, relative_cursor_position, mut slider) in &mut slider_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Some(position) = relative_cursor_position.normalized else {
+            continue;
+        };
+
+        let value = position.x.clamp(0., 1.);
+        if slider.value != value {
+            slider.value = value;
+        }
+    }
+}
+
+pub fn nudge_focused_slider(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    focus: Res<Focus>,
+    mut slider_query: Query<&mut Slider>,
+) {
+    let Some(mut slider) = focus
+        .0
+        .and_then(|focused| slider_query.get_mut(focused).ok())
+    else {
+        return;
+    };
+
+    if let Some(direction) = navigation_direction(&keys, &gamepads, &gamepad_buttons) {
+        if direction.x != 0. {
+            slider.value = (slider.value + direction.x * SLIDER_STEP).clamp(0., 1.);
+        }
+    }
+}
+
+pub fn draw_slider_fills(
+    settings: Res<Settings>,
+    slider_query: Query<(Ref<Slider>, &Children)>,
+    mut fill_query: Query<(&mut Style, &mut BackgroundColor), With<SliderFill>>,
+) {
+    for (slider, children) in &slider_query {
+        if !slider.is_changed() && !settings.is_changed() {
+            continue;
+        }
+
+        for &child in children {
+            if let Ok((mut style, mut background_color)) = fill_query.get_mut(child) {
+                style.width = Val::Percent(slider.value * 100.);
+                *background_color = settings.palette.accent().into();
+            }
+        }
+    }
+}
+
+pub fn press_toggles(
+    mut toggle_query: Query<&mut Toggle>,
+    mut activated_event_reader: EventReader<ButtonActivated>,
+) {
+    for ButtonActivated(button) in activated_event_reader.read() {
+        if let Ok(mut toggle) = toggle_query.get_mut(*button) {
+            toggle.on = !toggle.on;
+        }
+    }
+}
+
+pub fn draw_toggles(
+    settings: Res<Settings>,
+    mut toggle_query: Query<(Ref<Toggle>, &mut BackgroundColor)>,
+) {
+    for (toggle, mut background_color) in &mut toggle_query {
+        if !toggle.is_changed() && !settings.is_changed() {
+            continue;
+        }
+
+        *background_color = if toggle.on {
+            settings.palette.accent().into()
+        } else {
+            INACTIVE_COLOR.into()
+        };
+    }
+}
+
+pub fn navigate_focus(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    mut focus: ResMut<Focus>,
+    button_query: Query<
+        (Entity, &GlobalTransform, &InheritedVisibility),
+        (With<MenuButton>, Without<Disabled>),
+    >,
+    slider_query: Query<(), With<Slider>>,
+) {
+    let Some(direction) = navigation_direction(&keys, &gamepads, &gamepad_buttons) else {
+        return;
+    };
+
+    // Left and right adjust a focused slider instead of moving the focus.
+    if direction.x != 0.
+        && focus
+            .0
+            .is_some_and(|focused| slider_query.contains(focused))
+    {
+        return;
+    }
+
+    // UI coordinates grow downwards, which the navigation directions follow.
+    let buttons: Vec<(Entity, Vec2)> = button_query
+        .iter()
+        .filter(|(_, _, visibility)| visibility.get())
+        .map(|(entity, transform, _)| (entity, transform.translation().xy()))
+        .collect();
+
+    let Some((current, from)) = focus
+        .0
+        .and_then(|focused| buttons.iter().find(|(entity, _)| *entity == focused))
+        .copied()
+    else {
+        focus.0 = buttons
+            .iter()
+            .min_by(|(_, a), (_, b)| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)))
+            .map(|(entity, _)| *entity);
+        return;
+    };
+
+    let next = buttons
+        .iter()
+        .filter_map(|(entity, position)| {
+            let offset = *position - from;
+            let along = offset.dot(direction);
+            if *entity == current || along <= 1. {
+                return None;
+            }
+
+            Some((*entity, along + 2. * offset.perp_dot(direction).abs()))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(entity, _)| entity);
+
+    if next.is_some() {
+        focus.0 = next;
+    }
+}
+
+pub fn focus_hovered_buttons(
+    mut focus: ResMut<Focus>,
+    interaction_query: Query<
+        (Entity, &Interaction),
+        (Changed<Interaction>, With<MenuButton>, Without<Disabled>),
+    >,
+) {
+    for (entity, interaction) in &interaction_query {
+        if *interaction == Interaction::Hovered {
+            focus.0 = Some(entity);
+        }
+    }
+}
+
+pub fn activate_buttons(
+    settings: Res<Settings>,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    focus: Res<Focus>,
+    interaction_query: Query<
+        (Entity, &Interaction),
+        (Changed<Interaction>, With<MenuButton>, Without<Disabled>),
+    >,
+    enabled_query: Query<(), (With<MenuButton>, Without<Disabled>)>,
+    mut activated_event_writer: EventWriter<ButtonActivated>,
+) {
+    for (entity, interaction) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            activated_event_writer.send(ButtonActivated(entity));
+        }
+    }
+
+    let confirmed = keys.just_pressed(settings.bindings.confirm)
+        || gamepad_just_pressed(&gamepads, &gamepad_buttons, GamepadButtonType::South);
+
+    if let Some(focused) = focus
+        .0
+        .filter(|focused| confirmed && enabled_query.contains(*focused))
+    {
+        activated_event_writer.send(ButtonActivated(focused));
+    }
+}
+
+pub fn draw_button_focus(
+    settings: Res<Settings>,
+    focus: Res<Focus>,
+    mut button_query: Query<(Entity, &mut Outline, Has<Disabled>), With<MenuButton>>,
+) {
+    for (entity, mut outline, disabled) in &mut button_query {
+        let color = if focus.0 == Some(entity) && !disabled {
+            settings.palette.accent()
+        } else {
+            Color::NONE
+        };
+
+        if outline.color != color {
+            outline.color = color;
+        }
+    }
+}
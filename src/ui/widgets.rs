@@ -0,0 +1,189 @@
+use crate::i18n::Localized;
+use crate::ui::components::{Disabled, MenuButton, Slider, SliderFill, Toggle};
+use bevy::prelude::*;
+use bevy::ui::{FocusPolicy, RelativeCursorPosition};
+
+pub const PANEL_COLOR: Color = Color::srgba(0.176, 0.114, 0.192, 0.9);
+pub const TEXT_COLOR: Color = Color::srgb(0.969, 0.812, 0.569);
+pub const DISABLED_TEXT_COLOR: Color = Color::srgb(0.58, 0.48, 0.6);
+pub const INACTIVE_COLOR: Color = Color::srgb(0.38, 0.28, 0.4);
+pub const FONT_SIZE: f32 = 20.;
+
+// Static labels are translation keys, owned strings are shown as they are.
+pub enum Label {
+    Localized(&'static str),
+    Text(String),
+}
+
+impl From<&'static str> for Label {
+    fn from(key: &'static str) -> Self {
+        Label::Localized(key)
+    }
+}
+
+impl From<String> for Label {
+    fn from(text: String) -> Self {
+        Label::Text(text)
+    }
+}
+
+pub fn text_style(font_size: f32) -> TextStyle {
+    TextStyle {
+        font_size,
+        color: TEXT_COLOR,
+        ..default()
+    }
+}
+
+pub fn spawn_text(child_builder: &mut ChildBuilder, label: impl Into<Label>, style: TextStyle) {
+    match label.into() {
+        Label::Localized(key) => {
+            child_builder.spawn((TextBundle::from_section("", style), Localized(key)));
+        }
+        Label::Text(text) => {
+            child_builder.spawn(TextBundle::from_section(text, style));
+        }
+    }
+}
+
+pub fn spawn_label(child_builder: &mut ChildBuilder, label: impl Into<Label>) {
+    spawn_text(child_builder, label, text_style(FONT_SIZE));
+}
+
+pub fn spawn_slider(child_builder: &mut ChildBuilder, value: f32, marker: impl Bundle) {
+    child_builder
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Px(140.),
+                    height: Val::Px(16.),
+                    ..default()
+                },
+                background_color: INACTIVE_COLOR.into(),
+                ..default()
+            },
+            Interaction::default(),
+            RelativeCursorPosition::default(),
+            Outline::new(Val::Px(2.), Val::Px(2.), Color::NONE),
+            MenuButton,
+            Slider { value },
+            marker,
+        ))
+        .with_children(|child_builder| {
+            child_builder.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(value * 100.),
+                        height: Val::Percent(100.),
+                        ..default()
+                    },
+                    background_color: TEXT_COLOR.into(),
+                    ..default()
+                },
+                SliderFill,
+            ));
+        });
+}
+
+pub fn spawn_toggle(child_builder: &mut ChildBuilder, on: bool, marker: impl Bundle) {
+    child_builder.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Px(16.),
+                height: Val::Px(16.),
+                ..default()
+            },
+            background_color: if on { TEXT_COLOR } else { INACTIVE_COLOR }.into(),
+            ..default()
+        },
+        Interaction::default(),
+        Outline::new(Val::Px(2.), Val::Px(2.), Color::NONE),
+        MenuButton,
+        Toggle { on },
+        marker,
+    ));
+}
+
+pub fn spawn_row(child_builder: &mut ChildBuilder, spawn_children: impl FnOnce(&mut ChildBuilder)) {
+    child_builder
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.),
+                column_gap: Val::Px(12.),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceBetween,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(spawn_children);
+}
+
+pub fn spawn_menu_button(
+    child_builder: &mut ChildBuilder,
+    label: impl Into<Label>,
+    enabled: bool,
+    marker: impl Bundle,
+) -> Entity {
+    let mut button = child_builder.spawn((
+        ButtonBundle {
+            style: Style {
+                min_width: Val::Px(200.),
+                padding: UiRect::axes(Val::Px(16.), Val::Px(8.)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            background_color: PANEL_COLOR.into(),
+            ..default()
+        },
+        Outline::new(Val::Px(2.), Val::ZERO, Color::NONE),
+        MenuButton,
+        marker,
+    ));
+
+    if !enabled {
+        button.insert(Disabled);
+    }
+
+    let mut label_style = text_style(FONT_SIZE);
+    if !enabled {
+        label_style.color = DISABLED_TEXT_COLOR;
+    }
+
+    button.with_children(|child_builder| {
+        spawn_text(child_builder, label, label_style);
+    });
+
+    button.id()
+}
+
+pub fn spawn_screen(
+    commands: &mut Commands,
+    background_color: Color,
+    marker: impl Bundle,
+    spawn_children: impl FnOnce(&mut ChildBuilder),
+) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    position_type: PositionType::Absolute,
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    row_gap: Val::Px(12.),
+                    ..default()
+                },
+                background_color: background_color.into(),
+                focus_policy: FocusPolicy::Block,
+                z_index: ZIndex::Global(10),
+                ..default()
+            },
+            marker,
+        ))
+        .with_children(spawn_children);
+}
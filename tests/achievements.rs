@@ -0,0 +1,167 @@
+use bevy::prelude::*;
+use bottle_flip::achievements::assets::{Achievement, AchievementCatalog, Goal};
+use bottle_flip::achievements::events::AchievementUnlocked;
+use bottle_flip::achievements::resources::{
+    AchievementCatalogHandle, AchievementProgress, LandingStreak,
+};
+use bottle_flip::achievements::systems::{reset_landing_streak, track_achievements};
+use bottle_flip::bottle::components::{BottleContent, Spilled};
+use bottle_flip::bottle::events::BottleLanded;
+use bottle_flip::game::resources::Round;
+use bottle_flip::levels::assets::read_ron_file;
+use bottle_flip::levels::events::StartLevel;
+use bottle_flip::tricks::events::TricksLanded;
+use bottle_flip::tricks::resources::Trick;
+use std::collections::HashSet;
+use std::path::Path;
+
+// Tracks achievements with the given goals, each one's id being its index.
+fn tracking(goals: &[Goal]) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+    app.init_asset::<AchievementCatalog>();
+
+    let catalog = AchievementCatalog {
+        achievements: goals
+            .iter()
+            .enumerate()
+            .map(|(index, goal)| Achievement {
+                id: index.to_string(),
+                name: default(),
+                description: default(),
+                goal: *goal,
+            })
+            .collect(),
+    };
+    let handle = app
+        .world_mut()
+        .resource_mut::<Assets<AchievementCatalog>>()
+        .add(catalog);
+    app.insert_resource(AchievementCatalogHandle(handle));
+    app.insert_resource(AchievementProgress::default());
+    app.insert_resource(LandingStreak::default());
+    app.insert_resource(Round::default());
+    app.add_event::<BottleLanded>();
+    app.add_event::<TricksLanded>();
+    app.add_event::<StartLevel>();
+    app.add_event::<AchievementUnlocked>();
+    app.add_systems(Update, (reset_landing_streak, track_achievements).chain());
+
+    app
+}
+
+fn land(app: &mut App, standing: bool, tricks: &[Trick]) {
+    app.world_mut().send_event(BottleLanded {
+        upright: standing,
+        upside_down: false,
+        spilled: false,
+    });
+    if !tricks.is_empty() {
+        app.world_mut().send_event(TricksLanded {
+            tricks: tricks.to_vec(),
+            position: Vec2::ZERO,
+        });
+    }
+    app.update();
+}
+
+fn progress(app: &App, index: usize) -> u32 {
+    let achievement_progress = app.world().resource::<AchievementProgress>();
+    achievement_progress
+        .values
+        .get(&index.to_string())
+        .copied()
+        .unwrap_or_default()
+}
+
+// The indices of the achievements unlocked since the last call.
+fn unlocked(app: &mut App) -> Vec<usize> {
+    app.world_mut()
+        .resource_mut::<Events<AchievementUnlocked>>()
+        .drain()
+        .map(|AchievementUnlocked(index)| index)
+        .collect()
+}
+
+#[test]
+fn catalog_parses_with_unique_ids() {
+    let catalog: AchievementCatalog =
+        read_ron_file(&Path::new("assets").join("achievements.ron")).unwrap();
+
+    let mut ids = HashSet::new();
+    for achievement in &catalog.achievements {
+        assert!(
+            ids.insert(&achievement.id),
+            "{} is repeated",
+            achievement.id
+        );
+        assert!(achievement.name.iter().all(|name| !name.is_empty()));
+    }
+}
+
+#[test]
+fn streak_resets_on_a_miss_or_a_new_level() {
+    let mut app = tracking(&[Goal::Streak(3)]);
+
+    land(&mut app, true, &[]);
+    land(&mut app, true, &[]);
+    land(&mut app, false, &[]);
+    land(&mut app, true, &[]);
+    assert_eq!(progress(&app, 0), 2);
+
+    app.world_mut().send_event(StartLevel(0));
+    app.update();
+    land(&mut app, true, &[]);
+    land(&mut app, true, &[]);
+    assert_eq!(progress(&app, 0), 2);
+    assert!(unlocked(&mut app).is_empty());
+
+    land(&mut app, true, &[]);
+    assert_eq!(progress(&app, 0), 3);
+    assert_eq!(unlocked(&mut app), [0]);
+}
+
+#[test]
+fn tricks_and_landings_unlock_once() {
+    let mut app = tracking(&[
+        Goal::Trick(Trick::SingleFlip, 2),
+        Goal::Trick(Trick::DoubleFlip, 1),
+        Goal::Landings(2),
+    ]);
+
+    land(&mut app, true, &[Trick::SingleFlip]);
+    assert_eq!((progress(&app, 0), progress(&app, 1)), (1, 0));
+    assert!(unlocked(&mut app).is_empty());
+
+    land(&mut app, true, &[Trick::SingleFlip, Trick::CapLanding]);
+    assert_eq!(unlocked(&mut app), [0, 2]);
+
+    // Unlocked achievements don't count any further.
+    land(&mut app, true, &[Trick::SingleFlip]);
+    assert_eq!((progress(&app, 0), progress(&app, 2)), (2, 2));
+    assert!(unlocked(&mut app).is_empty());
+}
+
+#[test]
+fn empty_bottle_flip_needs_the_content_gone() {
+    let mut app = tracking(&[Goal::EmptyBottleFlip]);
+    let content = app.world_mut().spawn(BottleContent { index: 0 }).id();
+
+    land(&mut app, true, &[Trick::SingleFlip]);
+    assert_eq!(progress(&app, 0), 0);
+
+    app.world_mut().entity_mut(content).insert(Spilled);
+    land(&mut app, true, &[Trick::SingleFlip]);
+    assert_eq!(unlocked(&mut app), [0]);
+}
+
+#[test]
+fn invalid_round_earns_nothing() {
+    let mut app = tracking(&[Goal::Landings(1), Goal::Trick(Trick::SingleFlip, 1)]);
+    app.world_mut().resource_mut::<Round>().invalid = true;
+
+    land(&mut app, true, &[Trick::SingleFlip]);
+
+    assert_eq!((progress(&app, 0), progress(&app, 1)), (0, 0));
+    assert!(unlocked(&mut app).is_empty());
+}
@@ -0,0 +1,130 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bottle_flip::bottle::components::{Bottle, BottleContent, BottleContentJoint, GrabJoint};
+use bottle_flip::bottle::events::GrabInput;
+use bottle_flip::bottle::systems::BOTTLE_BODY_SIZE;
+use bottle_flip::levels::assets::Level;
+use bottle_flip::platforms::systems::FLOOR_LEVEL;
+use bottle_flip::simulation::{headless_app, restart_level, spawn_bottle_at, start_level, step};
+use bottle_flip::tuning::assets::PhysicsTuning;
+
+// How far joints may drift from their limits while the solver catches up.
+const JOINT_TOLERANCE: f32 = 2.;
+const UPRIGHT_TOLERANCE: f32 = 0.2;
+
+fn level() -> Level {
+    Level {
+        name: String::from("Test"),
+        bottle_spawn: Vec2::new(0., FLOOR_LEVEL + BOTTLE_BODY_SIZE.y / 2. + 20.),
+        ..default()
+    }
+}
+
+fn count<F: bevy::ecs::query::QueryFilter>(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world.query_filtered::<(), F>().iter(world).count()
+}
+
+fn bottle_pose(app: &mut App) -> (Position, Rotation) {
+    let world = app.world_mut();
+    let (position, rotation) = world
+        .query_filtered::<(&Position, &Rotation), With<Bottle>>()
+        .single(world);
+
+    (*position, *rotation)
+}
+
+#[test]
+fn bottle_dropped_upright_stays_upright() {
+    let mut app = headless_app();
+    spawn_bottle_at(&mut app, level().bottle_spawn);
+
+    step(&mut app, 300);
+
+    let (position, rotation) = bottle_pose(&mut app);
+    assert!(
+        rotation.as_radians().abs() < UPRIGHT_TOLERANCE,
+        "bottle tipped over to {} rad",
+        rotation.as_radians()
+    );
+    assert!(position.y > FLOOR_LEVEL, "bottle fell through the floor");
+}
+
+#[test]
+fn content_stays_within_joint_limits() {
+    let mut app = headless_app();
+    spawn_bottle_at(&mut app, Vec2::new(0., 100.));
+    step(&mut app, 2);
+
+    // Toss the bottle up with some spin so the contents slosh around.
+    let world = app.world_mut();
+    let mut bottle_query =
+        world.query_filtered::<(&mut LinearVelocity, &mut AngularVelocity), With<Bottle>>();
+    let (mut linear_velocity, mut angular_velocity) = bottle_query.single_mut(world);
+    linear_velocity.0 = Vec2::new(200., 1200.);
+    angular_velocity.0 = 8.;
+
+    let (min, max) = app.world().resource::<PhysicsTuning>().content_limits;
+
+    for _ in 0..240 {
+        step(&mut app, 1);
+
+        let (bottle_position, bottle_rotation) = bottle_pose(&mut app);
+        let world = app.world_mut();
+        let mut content_query = world.query_filtered::<&Position, With<BottleContent>>();
+
+        for content_position in content_query.iter(world) {
+            let local = bottle_rotation.inverse() * (content_position.0 - bottle_position.0);
+
+            assert!(
+                local.x.abs() <= JOINT_TOLERANCE,
+                "content left the bottle's axis: {local}"
+            );
+            assert!(
+                local.y >= min - JOINT_TOLERANCE && local.y <= max + JOINT_TOLERANCE,
+                "content moved past its limits: {local}"
+            );
+        }
+    }
+}
+
+#[test]
+fn restart_leaves_no_orphaned_content_joints() {
+    let mut app = headless_app();
+    start_level(&mut app, level());
+    step(&mut app, 10);
+
+    for _ in 0..3 {
+        restart_level(&mut app);
+        step(&mut app, 10);
+    }
+
+    assert_eq!(count::<With<Bottle>>(&mut app), 1);
+    assert_eq!(count::<With<BottleContent>>(&mut app), 2);
+    assert_eq!(count::<With<BottleContentJoint>>(&mut app), 2);
+}
+
+#[test]
+fn grab_and_release_create_and_remove_one_grab_joint() {
+    let mut app = headless_app();
+    start_level(&mut app, level());
+    step(&mut app, 30);
+
+    let (position, _) = bottle_pose(&mut app);
+
+    app.world_mut().send_event(GrabInput::Press(position.0));
+    step(&mut app, 1);
+    assert_eq!(count::<With<GrabJoint>>(&mut app), 1);
+
+    // Pressing again while holding the bottle doesn't grab it twice.
+    app.world_mut().send_event(GrabInput::Press(position.0));
+    app.world_mut()
+        .send_event(GrabInput::Drag(position.0 + Vec2::Y * 50.));
+    step(&mut app, 10);
+    assert_eq!(count::<With<GrabJoint>>(&mut app), 1);
+
+    app.world_mut()
+        .send_event(GrabInput::Release(position.0 + Vec2::Y * 50.));
+    step(&mut app, 1);
+    assert_eq!(count::<With<GrabJoint>>(&mut app), 0);
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use bevy::prelude::*;
+use bottle_flip::bottle::components::Bottle;
+use bottle_flip::bottle::systems::BOTTLE_BODY_SIZE;
+use bottle_flip::breakables::components::{Breakable, Fragment};
+use bottle_flip::levels::assets::{Level, PlatformSpec};
+use bottle_flip::platforms::components::PlatformKind;
+use bottle_flip::platforms::systems::{FLOOR_LEVEL, PLATES_SIZE};
+use bottle_flip::simulation::land_throw;
+use common::{count, drop_from, started};
+
+const PLATES_TOP: f32 = FLOOR_LEVEL + PLATES_SIZE.y;
+
+fn level(platforms: Vec<PlatformSpec>, glass_bottle: bool) -> Level {
+    Level {
+        name: String::from("Test"),
+        bottle_spawn: Vec2::new(0., 200.),
+        platforms,
+        glass_bottle,
+        ..default()
+    }
+}
+
+fn plates() -> PlatformSpec {
+    PlatformSpec::new(
+        PlatformKind::Plates,
+        Vec2::new(0., FLOOR_LEVEL + PLATES_SIZE.y / 2.),
+    )
+}
+
+#[test]
+fn glass_bottles_shatter_landing_hard_on_their_cap() {
+    let mut app = started(level(Vec::new(), true));
+
+    let throw_state = drop_from(&mut app, 200., 1200., true);
+    let landing = land_throw(&mut app, &throw_state).unwrap();
+
+    assert!(!landing.standing);
+    assert_eq!(count::<Bottle>(&mut app), 0);
+    assert!(count::<Fragment>(&mut app) > 0);
+}
+
+#[test]
+fn plastic_bottles_survive_the_same_fall() {
+    let mut app = started(level(Vec::new(), false));
+
+    let throw_state = drop_from(&mut app, 200., 1200., true);
+    land_throw(&mut app, &throw_state).unwrap();
+
+    assert_eq!(count::<Bottle>(&mut app), 1);
+    assert_eq!(count::<Fragment>(&mut app), 0);
+}
+
+#[test]
+fn glass_bottles_survive_landing_on_their_base() {
+    let mut app = started(level(Vec::new(), true));
+
+    let throw_state = drop_from(&mut app, 200., 1200., false);
+    land_throw(&mut app, &throw_state).unwrap();
+
+    assert_eq!(count::<Bottle>(&mut app), 1);
+}
+
+#[test]
+fn hard_landings_smash_plates() {
+    let mut app = started(level(vec![plates()], false));
+    assert_eq!(count::<Breakable>(&mut app), 4);
+
+    let throw_state = drop_from(&mut app, PLATES_SIZE.y + 200., 1200., false);
+    land_throw(&mut app, &throw_state);
+
+    assert!(count::<Breakable>(&mut app) < 4);
+    assert!(count::<Fragment>(&mut app) > 0);
+}
+
+#[test]
+fn gentle_landings_leave_plates_whole() {
+    let mut app = started(level(vec![plates()], false));
+
+    let throw_state = drop_from(
+        &mut app,
+        PLATES_TOP - FLOOR_LEVEL + BOTTLE_BODY_SIZE.y / 2. + 10.,
+        0.,
+        false,
+    );
+    land_throw(&mut app, &throw_state).unwrap();
+
+    assert_eq!(count::<Breakable>(&mut app), 4);
+    assert_eq!(count::<Fragment>(&mut app), 0);
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use bottle_flip::levels::assets::Level;
+use bottle_flip::platforms::systems::FLOOR_LEVEL;
+use bottle_flip::simulation::{headless_app, read_throw_state, start_level, step, ThrowState};
+use std::f32::consts::PI;
+
+pub fn started(level: Level) -> App {
+    let mut app = headless_app();
+    start_level(&mut app, level);
+    step(&mut app, 1);
+    app
+}
+
+// The bottle falling straight down from `height` above the floor at `speed`,
+// turned upside down when `cap_first`.
+pub fn drop_from(app: &mut App, height: f32, speed: f32, cap_first: bool) -> ThrowState {
+    let mut throw_state = read_throw_state(app.world_mut()).unwrap();
+    let bottle = throw_state.bottle.position;
+    let position = Vec2::new(0., FLOOR_LEVEL + height);
+
+    for body in std::iter::once(&mut throw_state.bottle)
+        .chain(&mut throw_state.contents)
+        .chain(&mut throw_state.cap)
+    {
+        let offset = body.position - bottle;
+        body.position = position + if cap_first { -offset } else { offset };
+        body.rotation += if cap_first { PI } else { 0. };
+        body.linear_velocity = Vec2::NEG_Y * speed;
+        body.angular_velocity = 0.;
+    }
+
+    throw_state
+}
+
+pub fn count<T: Component>(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world.query_filtered::<(), With<T>>().iter(world).count()
+}
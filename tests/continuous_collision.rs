@@ -0,0 +1,87 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bottle_flip::bottle::components::{Bottle, BottleContent};
+use bottle_flip::physics::CustomCollisionLayer;
+use bottle_flip::platforms::components::DynamicPlatform;
+use bottle_flip::simulation::{headless_app, spawn_bottle_at, step};
+use bottle_flip::tuning::assets::PhysicsTuning;
+
+const PLATFORM_Y: f32 = 0.;
+const PLATFORM_THICKNESS: f32 = 4.;
+// Far enough per step for the whole bottle to pass the platform in between.
+const THROW_SPEED: f32 = 12000.;
+const SPECULATIVE_MARGIN: f32 = 1.;
+
+fn spawn_thin_platform(app: &mut App) -> Entity {
+    app.world_mut()
+        .spawn((
+            TransformBundle::from_transform(Transform::from_xyz(0., PLATFORM_Y, 0.)),
+            RigidBody::Dynamic,
+            Collider::rectangle(300., PLATFORM_THICKNESS),
+            ColliderDensity(50.),
+            GravityScale(0.),
+            LockedAxes::ROTATION_LOCKED,
+            CollisionLayers::new(
+                CustomCollisionLayer::Platform,
+                [CustomCollisionLayer::Bottle, CustomCollisionLayer::Platform],
+            ),
+            DynamicPlatform,
+        ))
+        .id()
+}
+
+fn throw_bottle_down(app: &mut App) {
+    let world = app.world_mut();
+
+    let mut body_query =
+        world.query_filtered::<&mut LinearVelocity, Or<(With<Bottle>, With<BottleContent>)>>();
+    for mut linear_velocity in body_query.iter_mut(world) {
+        linear_velocity.0 = Vec2::NEG_Y * THROW_SPEED;
+    }
+}
+
+// Returns how far above the platform the bottle ends up.
+fn drop_bottle_on_platform(swept_ccd: bool) -> f32 {
+    let mut app = headless_app();
+
+    let mut physics_tuning = app.world_mut().resource_mut::<PhysicsTuning>();
+    physics_tuning.swept_ccd = swept_ccd;
+    physics_tuning.speculative_margin = Some(SPECULATIVE_MARGIN);
+    physics_tuning.max_linear_speed = THROW_SPEED * 2.;
+
+    let platform = spawn_thin_platform(&mut app);
+    spawn_bottle_at(&mut app, Vec2::new(0., 600.));
+
+    // Let the spawn commands and collision settings apply before throwing.
+    step(&mut app, 2);
+    throw_bottle_down(&mut app);
+    step(&mut app, 30);
+
+    let world = app.world_mut();
+    let bottle_y = world
+        .query_filtered::<&Position, With<Bottle>>()
+        .single(world)
+        .y;
+    let platform_y = world.get::<Position>(platform).unwrap().y;
+
+    bottle_y - platform_y
+}
+
+#[test]
+fn fast_bottle_does_not_tunnel_through_thin_platform() {
+    let height = drop_bottle_on_platform(true);
+
+    assert!(
+        height > 0.,
+        "bottle ended up {} below the platform",
+        -height
+    );
+}
+
+#[test]
+fn fast_bottle_tunnels_through_thin_platform_without_swept_ccd() {
+    // Makes sure the test above exercises the sweep, not the speculative contacts.
+    let height = drop_bottle_on_platform(false);
+
+    assert!(height < 0., "bottle stayed {height} above the platform");
+}
@@ -0,0 +1,45 @@
+use bottle_flip::daily::date::Date;
+use bottle_flip::levels::generator::{generate_level, platform_size};
+use bottle_flip::platforms::systems::FLOOR_LEVEL;
+
+const VIEW_HALF_WIDTH: f32 = 370.;
+
+#[test]
+fn dates_convert_to_and_from_days() {
+    assert_eq!(Date::from_days(0).to_string(), "1970-01-01");
+    assert_eq!(Date::from_days(11_017).to_string(), "2000-03-01");
+    assert_eq!("2024-02-29".parse::<Date>().unwrap().days(), 19_782);
+    assert!("2025-02-29".parse::<Date>().is_err());
+
+    for days in -1000..20_000 {
+        assert_eq!(Date::from_days(days).days(), days);
+    }
+}
+
+#[test]
+fn same_seed_generates_same_level() {
+    for seed in 0..50 {
+        let a = generate_level(String::from("A"), seed);
+        let b = generate_level(String::from("A"), seed);
+        assert_eq!(a.bottle_spawn, b.bottle_spawn);
+        assert_eq!(a.platforms, b.platforms);
+    }
+}
+
+#[test]
+fn generated_platforms_fit_the_view_without_overlapping() {
+    for seed in 0..200 {
+        let level = generate_level(String::new(), seed);
+        assert!(!level.platforms.is_empty(), "seed {seed} has no platforms");
+
+        let mut right = level.bottle_spawn.x;
+        for platform in &level.platforms {
+            let size = platform_size(platform.kind);
+            let left = platform.position.x - size.x / 2.;
+            assert!(left > right, "seed {seed} has overlapping platforms");
+            assert!(platform.position.x + size.x / 2. <= VIEW_HALF_WIDTH);
+            assert!(platform.position.y - size.y / 2. >= FLOOR_LEVEL - 0.01);
+            right = platform.position.x + size.x / 2.;
+        }
+    }
+}
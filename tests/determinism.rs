@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+use bottle_flip::bottle::systems::BOTTLE_BODY_SIZE;
+use bottle_flip::levels::assets::{Level, PlatformSpec};
+use bottle_flip::platforms::components::PlatformKind;
+use bottle_flip::platforms::systems::FLOOR_LEVEL;
+use bottle_flip::simulation::{headless_app, simulate_throw, BodyState, ThrowState};
+
+const STEPS: usize = 400;
+
+fn level() -> Level {
+    Level {
+        name: String::from("Determinism"),
+        bottle_spawn: Vec2::new(-150., FLOOR_LEVEL + BOTTLE_BODY_SIZE.y / 2.),
+        platforms: vec![PlatformSpec::new(
+            PlatformKind::CardboardBox,
+            Vec2::new(150., -88.),
+        )],
+        ..default()
+    }
+}
+
+// A release recorded from a direct grab throw.
+fn recorded_throw() -> ThrowState {
+    let position = Vec2::new(-150., -20.);
+    let linear_velocity = Vec2::new(520., 1350.);
+    let angular_velocity = -7.5;
+
+    let content = |offset: Vec2| BodyState {
+        position: position + offset,
+        rotation: 0.,
+        linear_velocity: linear_velocity + angular_velocity * offset.perp(),
+        angular_velocity: 0.,
+    };
+
+    ThrowState {
+        bottle: BodyState {
+            position,
+            rotation: 0.,
+            linear_velocity,
+            angular_velocity,
+        },
+        contents: [content(Vec2::Y * 18.), content(Vec2::NEG_Y * 18.)],
+        cap: None,
+    }
+}
+
+fn bits(body_state: &BodyState) -> [u32; 6] {
+    [
+        body_state.position.x.to_bits(),
+        body_state.position.y.to_bits(),
+        body_state.rotation.to_bits(),
+        body_state.linear_velocity.x.to_bits(),
+        body_state.linear_velocity.y.to_bits(),
+        body_state.angular_velocity.to_bits(),
+    ]
+}
+
+#[test]
+fn recorded_throw_replays_bit_for_bit() {
+    let final_poses: Vec<BodyState> = (0..3)
+        .map(|_| {
+            simulate_throw(&mut headless_app(), level(), &recorded_throw(), STEPS)
+                .expect("the level has a bottle")
+        })
+        .collect();
+
+    for final_pose in &final_poses[1..] {
+        assert_eq!(
+            bits(&final_poses[0]),
+            bits(final_pose),
+            "replays diverged: {:?} and {:?}",
+            final_poses[0],
+            final_pose
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bottle_flip::bottle::components::Bottle;
+use bottle_flip::forces::components::{ForceKind, ForceVolume, Gust};
+use bottle_flip::levels::assets::Level;
+use bottle_flip::platforms::systems::FLOOR_LEVEL;
+use bottle_flip::simulation::{headless_app, start_level, step};
+
+const STEP: f32 = 1. / 64.;
+
+fn volume(kind: ForceKind) -> ForceVolume {
+    ForceVolume {
+        position: Vec2::ZERO,
+        size: Vec2::new(200., 100.),
+        kind,
+    }
+}
+
+fn level(forces: Vec<ForceVolume>) -> Level {
+    Level {
+        name: String::from("Test"),
+        bottle_spawn: Vec2::new(0., 100.),
+        forces,
+        ..default()
+    }
+}
+
+fn bottle_position(app: &mut App) -> Vec2 {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Position, With<Bottle>>()
+        .single(world)
+        .0
+}
+
+fn bottle_velocity(app: &mut App) -> Vec2 {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&LinearVelocity, With<Bottle>>()
+        .single(world)
+        .0
+}
+
+#[test]
+fn volumes_only_act_inside() {
+    let wind = volume(ForceKind::Wind {
+        acceleration: Vec2::X * 100.,
+        gust: None,
+    });
+
+    assert_eq!(wind.acceleration(Vec2::ZERO, 0.), Vec2::X * 100.);
+    assert_eq!(wind.acceleration(Vec2::new(150., 0.), 0.), Vec2::ZERO);
+}
+
+#[test]
+fn gusts_add_to_the_wind_once_a_period() {
+    let gust = Gust {
+        strength: 2.,
+        period: 4.,
+    };
+
+    assert_eq!(gust.factor(0.), 1.);
+    assert!((gust.factor(1.) - 3.).abs() < 1e-4);
+    assert_eq!(gust.factor(3.), 1.);
+}
+
+#[test]
+fn fans_weaken_away_from_their_edge() {
+    let fan = volume(ForceKind::Fan {
+        acceleration: Vec2::X * 1000.,
+    });
+
+    let near = fan.acceleration(Vec2::new(-90., 0.), 0.);
+    let far = fan.acceleration(Vec2::new(90., 0.), 0.);
+    assert!(near.x > far.x * 5.);
+    assert!(far.x > 0.);
+}
+
+#[test]
+fn conveyors_bring_bodies_up_to_speed() {
+    let conveyor = volume(ForceKind::Conveyor { speed: 60. });
+
+    let mut velocity = Vec2::new(0., -10.);
+    for _ in 0..64 {
+        velocity += conveyor.belt_velocity_change(velocity, STEP);
+    }
+
+    assert!((velocity.x - 60.).abs() < 1.);
+    assert_eq!(velocity.y, -10.);
+}
+
+#[test]
+fn conveyors_only_carry_what_touches_the_belt() {
+    // Tall enough for the bottle to fall through most of it first.
+    let conveyor = ForceVolume {
+        position: Vec2::new(0., FLOOR_LEVEL + 200.),
+        size: Vec2::new(400., 400.),
+        kind: ForceKind::Conveyor { speed: 120. },
+    };
+
+    let mut app = headless_app();
+    start_level(&mut app, level(vec![conveyor]));
+    step(&mut app, 8);
+    assert_eq!(bottle_velocity(&mut app).x, 0.);
+
+    step(&mut app, 64);
+    assert!(
+        bottle_velocity(&mut app).x > 60.,
+        "bottle moves at {} on the belt",
+        bottle_velocity(&mut app).x
+    );
+}
+
+#[test]
+fn bottle_floats_without_gravity_in_a_bubble() {
+    let bubble = ForceVolume {
+        position: Vec2::new(0., 100.),
+        size: Vec2::splat(300.),
+        kind: ForceKind::LowGravity { gravity_scale: 0. },
+    };
+
+    let mut app = headless_app();
+    start_level(&mut app, level(vec![bubble]));
+    let start = bottle_position(&mut app);
+    step(&mut app, 64);
+
+    assert!(
+        (bottle_position(&mut app) - start).length() < 5.,
+        "bottle drifted from {start} to {}",
+        bottle_position(&mut app)
+    );
+}
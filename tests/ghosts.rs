@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+use bottle_flip::bottle::systems::BOTTLE_BODY_SIZE;
+use bottle_flip::game::GamePlugin;
+use bottle_flip::ghost::resources::{Ghost, GhostFrame};
+use bottle_flip::ghost::systems::{ghost_key, read_ghost, write_ghost};
+use bottle_flip::ghost::GhostPlugin;
+use bottle_flip::leaderboard::backend::Submission;
+use bottle_flip::levels::assets::Level;
+use bottle_flip::physics::TIMESTEP_HZ;
+use bottle_flip::platforms::systems::FLOOR_LEVEL;
+use bottle_flip::simulation::{
+    headless_app, land_throw, read_throw_state, start_level, BodyState, ThrowState,
+};
+use bottle_flip::storage;
+use std::f32::consts::FRAC_PI_2;
+
+fn ghost(frame_count: usize) -> Ghost {
+    Ghost {
+        level: String::from("levels/01_first_flip.level.ron"),
+        score: 3,
+        throws: vec![ThrowState {
+            bottle: BodyState {
+                position: Vec2::new(-150., -20.),
+                rotation: 0.25,
+                linear_velocity: Vec2::new(620., 1180.),
+                angular_velocity: -9.5,
+            },
+            ..default()
+        }],
+        frames: (0..frame_count)
+            .map(|index| GhostFrame {
+                position: Vec2::new(index as f32 * 0.5, -88. + index as f32 * 0.25),
+                rotation: index as f32 * 0.1,
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn exported_ghost_imports_unchanged() {
+    let ghost = ghost(120);
+
+    let file = write_ghost(&ghost).expect("ghosts serialize");
+
+    assert_eq!(read_ghost(&file), Ok(ghost));
+}
+
+#[test]
+fn ghost_with_too_many_frames_is_rejected() {
+    let ghost = ghost(TIMESTEP_HZ as usize * 60 * 5 + 1);
+
+    let file = write_ghost(&ghost).expect("ghosts serialize");
+
+    assert!(read_ghost(&file).is_err());
+}
+
+fn level() -> Level {
+    Level {
+        name: String::from("Test"),
+        bottle_spawn: Vec2::new(0., FLOOR_LEVEL + BOTTLE_BODY_SIZE.y / 2. + 10.),
+        ..default()
+    }
+}
+
+// The resting bottle picked up and let go of lying on its side, so it falls
+// over where it lands.
+fn tipped_over(resting: &ThrowState) -> ThrowState {
+    let mut throw_state = resting.clone();
+    let pivot = resting.bottle.position;
+    let position = pivot + Vec2::Y * BOTTLE_BODY_SIZE.x;
+
+    for body in std::iter::once(&mut throw_state.bottle)
+        .chain(&mut throw_state.contents)
+        .chain(&mut throw_state.cap)
+    {
+        body.position = position + Vec2::from_angle(FRAC_PI_2).rotate(body.position - pivot);
+        body.rotation += FRAC_PI_2;
+        body.linear_velocity = Vec2::ZERO;
+        body.angular_velocity = 0.;
+    }
+
+    throw_state
+}
+
+#[test]
+fn ghost_of_a_round_ending_in_a_miss_verifies() {
+    // Saves go to the working directory, so the round is played in an empty
+    // one rather than over the player's own progress and ghosts.
+    let directory = std::env::temp_dir().join(format!("bottle_flip_ghosts_{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    std::env::set_current_dir(&directory).unwrap();
+
+    let mut app = headless_app();
+    app.add_plugins((GamePlugin, GhostPlugin));
+    start_level(&mut app, level());
+
+    for _ in 0..2 {
+        let resting = read_throw_state(app.world_mut()).unwrap();
+        let dropped = resting.launched(resting.bottle.position + Vec2::Y * 10., Vec2::ZERO, 0.);
+        let landing = land_throw(&mut app, &dropped).unwrap();
+        assert!(landing.standing);
+    }
+    let resting = read_throw_state(app.world_mut()).unwrap();
+    let landing = land_throw(&mut app, &tipped_over(&resting)).unwrap();
+    assert!(!landing.standing);
+    app.update();
+
+    let ghost = storage::load_ron::<Ghost>(&ghost_key("simulation")).expect("a new best was saved");
+    assert_eq!(ghost.score, 300);
+    assert_eq!(ghost.throws.len(), 3);
+    assert!(Submission::from_ghost("Ada", &ghost)
+        .verify(level())
+        .is_ok());
+}
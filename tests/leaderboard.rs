@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use bottle_flip::bottle::systems::BOTTLE_BODY_SIZE;
+use bottle_flip::leaderboard::backend::{
+    LeaderboardBackend, LeaderboardEntry, LocalLeaderboard, Submission, MAX_ENTRIES,
+};
+use bottle_flip::levels::assets::Level;
+use bottle_flip::platforms::systems::FLOOR_LEVEL;
+use bottle_flip::simulation::{headless_app, read_throw_state, start_level, ThrowState};
+
+const LEVEL_PATH: &str = "levels/test.level.ron";
+
+fn level() -> Level {
+    Level {
+        name: String::from("Test"),
+        bottle_spawn: Vec2::new(0., FLOOR_LEVEL + BOTTLE_BODY_SIZE.y / 2. + 10.),
+        ..default()
+    }
+}
+
+// Letting go of the bottle right where it spawns, so it drops onto its base.
+fn drop_in_place() -> ThrowState {
+    let mut app = headless_app();
+    start_level(&mut app, level());
+    read_throw_state(app.world_mut()).expect("the level has a bottle")
+}
+
+fn submission(player: &str, score: u32, throws: Vec<ThrowState>) -> Submission {
+    Submission {
+        player: player.to_string(),
+        level: LEVEL_PATH.to_string(),
+        score,
+        throws,
+    }
+}
+
+#[test]
+fn local_leaderboard_keeps_each_players_best_first() {
+    let leaderboard = LocalLeaderboard::in_memory();
+    for (player, score) in [("Ada", 300), ("Bo", 600), ("Ada", 100), ("Cy", 1000)] {
+        leaderboard
+            .submit(&submission(player, score, Vec::new()))
+            .unwrap();
+    }
+
+    let entries = leaderboard.top_scores(LEVEL_PATH).unwrap();
+    let entries: Vec<(&str, u32)> = entries
+        .iter()
+        .map(|LeaderboardEntry { player, score }| (player.as_str(), *score))
+        .collect();
+    assert_eq!(entries, [("Cy", 1000), ("Bo", 600), ("Ada", 300)]);
+}
+
+#[test]
+fn local_leaderboard_is_capped() {
+    let leaderboard = LocalLeaderboard::in_memory();
+    for index in 0..MAX_ENTRIES as u32 + 5 {
+        leaderboard
+            .submit(&submission(&format!("Player {index}"), index, Vec::new()))
+            .unwrap();
+    }
+
+    let entries = leaderboard.top_scores(LEVEL_PATH).unwrap();
+    assert_eq!(entries.len(), MAX_ENTRIES);
+    assert_eq!(entries[0].score, MAX_ENTRIES as u32 + 4);
+}
+
+#[test]
+fn replayed_round_earns_its_claimed_score() {
+    let throws = vec![drop_in_place(), drop_in_place()];
+
+    // Two landings in a row score 100 and then 200.
+    assert!(submission("Ada", 300, throws.clone())
+        .verify(level())
+        .is_ok());
+    assert!(submission("Ada", 1000, throws).verify(level()).is_err());
+}
+
+#[test]
+fn teleported_throw_is_rejected() {
+    // Lands standing like `drop_in_place`, but far out of reach of the spawn.
+    let resting = drop_in_place();
+    let throw_state = resting.launched(
+        resting.bottle.position + Vec2::new(1000., 0.),
+        Vec2::ZERO,
+        0.,
+    );
+
+    assert!(submission("Ada", 100, vec![throw_state])
+        .verify(level())
+        .is_err());
+}
+
+#[test]
+fn implausible_throw_is_rejected() {
+    let mut throw_state = drop_in_place();
+    throw_state.bottle.linear_velocity = Vec2::new(0., 5000.);
+
+    assert!(submission("Ada", 100, vec![throw_state])
+        .verify(level())
+        .is_err());
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use bevy::prelude::*;
+use bottle_flip::bottle::components::{BottleCap, CapJoint, LooseCap, Spilled};
+use bottle_flip::bottle::systems::BOTTLE_BODY_SIZE;
+use bottle_flip::levels::assets::Level;
+use bottle_flip::simulation::{land_throw, read_throw_state};
+use common::{count, drop_from, started};
+
+fn level(loose_cap: bool) -> Level {
+    Level {
+        name: String::from("Test"),
+        bottle_spawn: Vec2::new(0., 200.),
+        loose_cap,
+        ..default()
+    }
+}
+
+#[test]
+fn only_loose_caps_are_recorded_with_the_throw() {
+    let mut app = started(level(true));
+    assert!(read_throw_state(app.world_mut()).unwrap().cap.is_some());
+
+    let mut app = started(level(false));
+    assert!(read_throw_state(app.world_mut()).unwrap().cap.is_none());
+}
+
+#[test]
+fn hard_cap_first_landings_pop_the_cap_and_spill() {
+    let mut app = started(level(true));
+
+    let throw_state = drop_from(&mut app, 200., 1200., true);
+    let landing = land_throw(&mut app, &throw_state).unwrap();
+
+    assert!(landing.spilled);
+    assert!(!landing.standing);
+    assert_eq!(count::<LooseCap>(&mut app), 1);
+    assert_eq!(count::<BottleCap>(&mut app), 0);
+    assert_eq!(count::<CapJoint>(&mut app), 0);
+    assert_eq!(count::<Spilled>(&mut app), 2);
+}
+
+#[test]
+fn fixed_caps_stay_on_through_the_same_fall() {
+    let mut app = started(level(false));
+
+    let throw_state = drop_from(&mut app, 200., 1200., true);
+    let landing = land_throw(&mut app, &throw_state).unwrap();
+
+    assert!(!landing.spilled);
+    assert_eq!(count::<BottleCap>(&mut app), 1);
+    assert_eq!(count::<Spilled>(&mut app), 0);
+}
+
+#[test]
+fn gentle_landings_keep_the_cap_on() {
+    let mut app = started(level(true));
+
+    let throw_state = drop_from(&mut app, BOTTLE_BODY_SIZE.y / 2. + 10., 0., false);
+    let landing = land_throw(&mut app, &throw_state).unwrap();
+
+    assert!(landing.standing);
+    assert_eq!(count::<CapJoint>(&mut app), 1);
+    assert_eq!(count::<Spilled>(&mut app), 0);
+}
@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+use bottle_flip::levels::assets::{load_campaign, Level, PlatformSpec};
+use bottle_flip::objectives::resources::{
+    Objective, ObjectiveLanding, ObjectiveStatus, Objectives,
+};
+use bottle_flip::platforms::components::PlatformKind;
+use std::path::Path;
+
+fn landing_on(platform: usize) -> ObjectiveLanding {
+    ObjectiveLanding {
+        platforms: vec![platform],
+        ..default()
+    }
+}
+
+#[test]
+fn completing_every_objective_earns_stars_by_score() {
+    let mut objectives = Objectives::new(&[Objective::LandOn(1), Objective::MaxThrows(2)]);
+
+    objectives.add_throw();
+    objectives.add_landing(&landing_on(0));
+    assert!(!objectives.is_complete());
+    assert_eq!(objectives.stars(1000, 300), 0);
+
+    objectives.add_throw();
+    objectives.add_landing(&landing_on(1));
+    assert!(objectives.is_complete());
+    assert_eq!(objectives.stars(100, 300), 1);
+    assert_eq!(objectives.stars(300, 300), 2);
+    assert_eq!(objectives.stars(600, 300), 3);
+}
+
+#[test]
+fn running_out_of_throws_or_time_fails_objectives() {
+    let mut objectives = Objectives::new(&[
+        Objective::MaxThrows(1),
+        Objective::FlipsWithin {
+            flips: 1,
+            seconds: 10.,
+        },
+    ]);
+
+    objectives.add_throw();
+    assert!(!objectives.is_failed());
+    objectives.add_throw();
+    assert!(objectives.is_failed());
+    objectives.tick(11.);
+    objectives.add_landing(&ObjectiveLanding {
+        flipped: true,
+        ..default()
+    });
+
+    assert!(objectives
+        .objectives
+        .iter()
+        .all(|(_, status)| *status == ObjectiveStatus::Failed));
+    assert!(!objectives.is_complete());
+
+    // Time running out alone fails the round too.
+    let mut objectives = Objectives::new(&[Objective::FlipsWithin {
+        flips: 1,
+        seconds: 10.,
+    }]);
+    objectives.tick(9.);
+    assert!(!objectives.is_failed());
+    objectives.tick(2.);
+    assert!(objectives.is_failed());
+}
+
+#[test]
+fn removing_a_platform_keeps_targets_in_place() {
+    let mut level = Level {
+        name: String::from("Test"),
+        bottle_spawn: Vec2::ZERO,
+        platforms: vec![
+            PlatformSpec::new(PlatformKind::CardboardBox, Vec2::ZERO),
+            PlatformSpec::new(PlatformKind::TrashCan, Vec2::X * 100.),
+            PlatformSpec::new(PlatformKind::Shelf, Vec2::X * 200.),
+        ],
+        objectives: vec![
+            Objective::LandOn(0),
+            Objective::LandOn(2),
+            Objective::Rotations(2),
+        ],
+        ..default()
+    };
+
+    level.remove_platform(0);
+
+    assert_eq!(level.platforms[1].kind, PlatformKind::Shelf);
+    assert_eq!(
+        level.objectives,
+        vec![Objective::LandOn(1), Objective::Rotations(2)]
+    );
+}
+
+#[test]
+fn campaign_objectives_target_existing_platforms() {
+    let campaign = load_campaign(Path::new("assets")).unwrap();
+
+    for (path, level) in campaign {
+        for objective in &level.objectives {
+            if let Objective::LandOn(platform) = objective {
+                assert!(
+                    *platform < level.platforms.len(),
+                    "{path} targets a missing platform"
+                );
+            }
+        }
+    }
+}
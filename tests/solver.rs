@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+use bottle_flip::bottle::systems::BOTTLE_BODY_SIZE;
+use bottle_flip::levels::assets::Level;
+use bottle_flip::platforms::systems::FLOOR_LEVEL;
+use bottle_flip::simulation::{headless_app, start_level, throw_and_land};
+use bottle_flip::solver::search::{solve, SolverConfig, Target};
+
+fn level() -> Level {
+    Level {
+        name: String::from("Test"),
+        bottle_spawn: Vec2::new(0., FLOOR_LEVEL + BOTTLE_BODY_SIZE.y / 2. + 10.),
+        ..default()
+    }
+}
+
+#[test]
+fn solutions_land_upright_when_replayed() {
+    let config = SolverConfig {
+        max_solutions: 2,
+        ..default()
+    };
+    let solutions = solve(&level(), Target::Anywhere, &config);
+    assert_eq!(solutions.len(), 2);
+
+    for solution in solutions {
+        let mut app = headless_app();
+        start_level(&mut app, level());
+        assert_eq!(throw_and_land(&mut app, &solution.throw_state), Some(true));
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+use bottle_flip::bottle::systems::BOTTLE_BODY_SIZE;
+use bottle_flip::levels::assets::Level;
+use bottle_flip::platforms::components::TargetZone;
+use bottle_flip::platforms::systems::FLOOR_LEVEL;
+use bottle_flip::simulation::{headless_app, land_throw, read_throw_state, start_level, step};
+
+fn target(offset: f32) -> TargetZone {
+    TargetZone {
+        offset,
+        radius: 60.,
+        rings: 3,
+        points: 300,
+    }
+}
+
+fn level(ground_targets: Vec<TargetZone>) -> Level {
+    Level {
+        name: String::from("Test"),
+        bottle_spawn: Vec2::new(0., FLOOR_LEVEL + BOTTLE_BODY_SIZE.y / 2. + 20.),
+        ground_targets,
+        ..default()
+    }
+}
+
+// Lets the bottle drop from its spawn point and settle.
+fn drop_bottle(level: Level) -> Option<u32> {
+    let mut app = headless_app();
+    start_level(&mut app, level);
+    step(&mut app, 1);
+
+    let throw_state = read_throw_state(app.world_mut()).unwrap();
+    let landing = land_throw(&mut app, &throw_state).unwrap();
+    assert!(landing.upright);
+
+    landing.target_hit.map(|target_hit| target_hit.points)
+}
+
+#[test]
+fn rings_score_less_away_from_the_centre() {
+    let target = target(0.);
+
+    assert_eq!(target.score(0.), 300);
+    assert_eq!(target.score(25.), 200);
+    assert_eq!(target.score(50.), 100);
+    assert_eq!(target.score(61.), 0);
+}
+
+#[test]
+fn landing_in_a_ground_target_scores_its_ring() {
+    assert_eq!(drop_bottle(level(vec![target(0.)])), Some(300));
+    assert_eq!(drop_bottle(level(vec![target(45.)])), Some(100));
+}
+
+#[test]
+fn landing_beside_a_target_scores_nothing() {
+    assert_eq!(drop_bottle(level(vec![target(200.)])), None);
+}
@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use bottle_flip::tricks::resources::{Touch, Trick, TrickTracker};
+use std::f32::consts::{PI, TAU};
+
+// Turns the bottle in steps small enough to follow, like the physics steps do.
+fn spin(trick_tracker: &mut TrickTracker, turn: f32) {
+    let steps = (turn.abs() / 0.4).ceil() as usize;
+    for _ in 0..steps {
+        let angle = trick_tracker.last_angle + turn / steps as f32;
+        trick_tracker.turn_to(Rot2::radians(angle).as_radians());
+    }
+}
+
+#[test]
+fn counts_flips_through_wrapped_angles() {
+    let mut trick_tracker = TrickTracker::start(0., 300.);
+    spin(&mut trick_tracker, -2. * TAU);
+
+    assert!((trick_tracker.rotation + 2. * TAU).abs() < 1e-3);
+    assert_eq!(trick_tracker.tricks(true, false), vec![Trick::DoubleFlip]);
+}
+
+#[test]
+fn spinning_against_the_throw_is_a_reverse_flip() {
+    let mut trick_tracker = TrickTracker::start(0., 300.);
+    spin(&mut trick_tracker, TAU);
+
+    assert_eq!(
+        trick_tracker.tricks(true, false),
+        vec![Trick::SingleFlip, Trick::ReverseFlip]
+    );
+}
+
+#[test]
+fn bouncing_off_a_box_onto_the_floor() {
+    let (cardboard_box, ground) = (Entity::from_raw(1), Entity::from_raw(2));
+    let mut trick_tracker = TrickTracker::start(0., 300.);
+    trick_tracker.touchdown_angle = Some(PI / 2.);
+    trick_tracker.touched = vec![
+        (cardboard_box, Touch::Dynamic { moving: false }),
+        (ground, Touch::Ground),
+    ];
+    trick_tracker.resting_on = vec![ground];
+
+    assert_eq!(
+        trick_tracker.tricks(true, false),
+        vec![Trick::SideRoll, Trick::Bounce]
+    );
+}
+
+#[test]
+fn resting_on_the_cap_is_a_cap_landing() {
+    let ground = Entity::from_raw(1);
+    let mut trick_tracker = TrickTracker::start(0., -300.);
+    spin(&mut trick_tracker, PI);
+    trick_tracker.touched = vec![(ground, Touch::Ground)];
+    trick_tracker.resting_on = vec![ground];
+    trick_tracker.cap_contact = true;
+
+    assert_eq!(trick_tracker.tricks(false, true), vec![Trick::CapLanding]);
+    assert!(trick_tracker.tricks(false, false).is_empty());
+}